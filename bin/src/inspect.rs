@@ -0,0 +1,242 @@
+use crate::{source_map::SourceMap, types::FileFormat};
+use fluentbase_core::helpers::rwasm_module;
+use fluentbase_types::{SysFuncIdx, STATE_DEPLOY, STATE_MAIN};
+use rwasm::{engine::bytecode::Instruction, rwasm::RwasmModule};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+};
+use strum::IntoEnumIterator;
+
+/// Loads an rWASM module either from a `.rwasm` binary or by compiling a `.wat`/`.wasm` file.
+pub(crate) fn load_rwasm_module(file_in_path: &str) -> RwasmModule {
+    let file_bytes = fs::read(file_in_path).unwrap();
+    match FileFormat::from_path(file_in_path) {
+        FileFormat::Rwasm => RwasmModule::new(&file_bytes).unwrap(),
+        FileFormat::Wat => rwasm_module(&wat::parse_bytes(&file_bytes).unwrap()).unwrap(),
+        FileFormat::Wasm => rwasm_module(&file_bytes).unwrap(),
+    }
+}
+
+pub(crate) fn sys_func_name(func_idx: u32) -> Option<String> {
+    SysFuncIdx::iter()
+        .find(|v| Into::<u32>::into(*v) == func_idx)
+        .map(|v| format!("{:?}", v))
+}
+
+fn state_name(state: u32) -> &'static str {
+    match state {
+        STATE_MAIN => "main",
+        STATE_DEPLOY => "deploy",
+        _ => "unknown",
+    }
+}
+
+fn format_instr(instr: &Instruction) -> String {
+    match instr {
+        Instruction::Call(func_idx) => match sys_func_name(func_idx.to_u32()) {
+            Some(name) => format!("{:?} ; {}", instr, name),
+            None => format!("{:?}", instr),
+        },
+        _ => format!("{:?}", instr),
+    }
+}
+
+/// Prints every instruction with its offset, split by rWASM functions.
+pub(crate) fn disasm(rwasm_module: &RwasmModule) -> String {
+    let source_map = SourceMap::from_rwasm_module(rwasm_module);
+    let mut fn_begins = BTreeMap::new();
+    for func in source_map.functions.iter() {
+        fn_begins.insert(func.begin as usize, func.fn_index);
+    }
+    let mut result = String::new();
+    for (offset, instr) in rwasm_module.code_section.instr.iter().enumerate() {
+        if let Some(fn_index) = fn_begins.get(&offset) {
+            result.push_str(&format!("func {}:\n", fn_index));
+        }
+        result.push_str(&format!("{:>8}: {}\n", offset, format_instr(instr)));
+    }
+    result
+}
+
+/// Summary of an rWASM module.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct ModuleInfo {
+    pub(crate) instruction_count: usize,
+    pub(crate) function_count: usize,
+    pub(crate) memory_section_len: usize,
+    pub(crate) element_section_len: usize,
+    pub(crate) globals: BTreeSet<u32>,
+    pub(crate) tables: BTreeSet<u32>,
+    pub(crate) data_segments: BTreeSet<u32>,
+    pub(crate) element_segments: BTreeSet<u32>,
+    /// Number of calls per imported function
+    pub(crate) imports: BTreeMap<u32, usize>,
+    /// `(state, function)` pairs the state router dispatches to
+    pub(crate) router_entries: Vec<(u32, u32)>,
+}
+
+impl ModuleInfo {
+    pub(crate) fn new(rwasm_module: &RwasmModule) -> Self {
+        let mut info = Self {
+            instruction_count: rwasm_module.code_section.instr.len(),
+            function_count: rwasm_module.func_section.len(),
+            memory_section_len: rwasm_module.memory_section.len(),
+            element_section_len: rwasm_module.element_section.len(),
+            ..Default::default()
+        };
+        // the router reads the state with `SYS_STATE` and calls a function per matched state
+        let mut in_router = false;
+        let mut pending_state = None;
+        for instr in rwasm_module.code_section.instr.iter() {
+            match instr {
+                Instruction::Call(func_idx) => {
+                    let func_idx = func_idx.to_u32();
+                    *info.imports.entry(func_idx).or_default() += 1;
+                    if func_idx == SysFuncIdx::SYS_STATE as u32 {
+                        in_router = info.router_entries.is_empty();
+                    }
+                }
+                Instruction::CallInternal(func) if in_router => {
+                    if let Some(state) = pending_state.take() {
+                        info.router_entries.push((state, func.to_u32()));
+                    }
+                }
+                Instruction::I32Const(value) if in_router => {
+                    pending_state = Some(value.as_i32() as u32);
+                }
+                Instruction::Return(_) => in_router = false,
+                Instruction::GlobalGet(idx) | Instruction::GlobalSet(idx) => {
+                    info.globals.insert(idx.to_u32());
+                }
+                Instruction::TableSize(idx)
+                | Instruction::TableGrow(idx)
+                | Instruction::TableGet(idx)
+                | Instruction::TableSet(idx) => {
+                    info.tables.insert(idx.to_u32());
+                }
+                Instruction::MemoryInit(idx) | Instruction::DataDrop(idx) => {
+                    info.data_segments.insert(idx.to_u32());
+                }
+                Instruction::TableInit(idx) | Instruction::ElemDrop(idx) => {
+                    info.element_segments.insert(idx.to_u32());
+                }
+                _ => {}
+            }
+        }
+        info
+    }
+
+    pub(crate) fn render(&self) -> String {
+        let mut result = String::new();
+        result.push_str(&format!("instructions: {}\n", self.instruction_count));
+        result.push_str(&format!("functions: {}\n", self.function_count));
+        result.push_str(&format!(
+            "memory section: {} bytes\n",
+            self.memory_section_len
+        ));
+        result.push_str(&format!(
+            "element section: {} entries\n",
+            self.element_section_len
+        ));
+        result.push_str(&format!("globals: {}\n", self.globals.len()));
+        result.push_str(&format!("tables: {}\n", self.tables.len()));
+        result.push_str(&format!("data segments: {}\n", self.data_segments.len()));
+        result.push_str(&format!(
+            "element segments: {}\n",
+            self.element_segments.len()
+        ));
+        result.push_str("imports:\n");
+        for (func_idx, calls) in self.imports.iter() {
+            let name = sys_func_name(*func_idx).unwrap_or_else(|| format!("0x{:04x}", func_idx));
+            result.push_str(&format!("  {}: {} call(s)\n", name, calls));
+        }
+        result.push_str("state router:\n");
+        for (state, func) in self.router_entries.iter() {
+            result.push_str(&format!(
+                "  {} ({}) => func {}\n",
+                state_name(*state),
+                state,
+                func
+            ));
+        }
+        result
+    }
+}
+
+/// Compares two modules instruction by instruction and returns the differing lines, prefixed
+/// with `-` for the left and `+` for the right module.
+pub(crate) fn diff(left: &RwasmModule, right: &RwasmModule) -> String {
+    let mut result = String::new();
+    let (left_info, right_info) = (ModuleInfo::new(left), ModuleInfo::new(right));
+    if left_info != right_info {
+        for (l, r) in left_info.render().lines().zip(right_info.render().lines()) {
+            if l != r {
+                result.push_str(&format!("- {}\n+ {}\n", l, r));
+            }
+        }
+    }
+    let left_instr = &left.code_section.instr;
+    let right_instr = &right.code_section.instr;
+    for offset in 0..left_instr.len().max(right_instr.len()) {
+        let (l, r) = (left_instr.get(offset), right_instr.get(offset));
+        if l == r {
+            continue;
+        }
+        if let Some(l) = l {
+            result.push_str(&format!("-{:>8}: {}\n", offset, format_instr(l)));
+        }
+        if let Some(r) = r {
+            result.push_str(&format!("+{:>8}: {}\n", offset, format_instr(r)));
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compile(wat: &str) -> RwasmModule {
+        rwasm_module(&wat::parse_str(wat).unwrap()).unwrap()
+    }
+
+    const WAT: &str = r#"
+(module
+  (type (;0;) (func (param i32 i32)))
+  (import "fluentbase_v1alpha" "_sys_write" (func $_sys_write (type 0)))
+  (func $main
+    i32.const 0
+    i32.const 0
+    call $_sys_write)
+  (func $deploy)
+  (memory 1)
+  (export "main" (func $main))
+  (export "deploy" (func $deploy)))
+    "#;
+
+    #[test]
+    fn test_info_reports_imports_and_router() {
+        let info = ModuleInfo::new(&compile(WAT));
+        assert_eq!(info.imports.get(&(SysFuncIdx::SYS_WRITE as u32)), Some(&1));
+        let states = info
+            .router_entries
+            .iter()
+            .map(|(state, _)| *state)
+            .collect::<BTreeSet<_>>();
+        assert!(states.contains(&STATE_MAIN));
+        assert!(states.contains(&STATE_DEPLOY));
+        assert!(info.render().contains("SYS_WRITE: 1 call(s)"));
+    }
+
+    #[test]
+    fn test_disasm_and_diff() {
+        let module = compile(WAT);
+        let disasm = disasm(&module);
+        assert!(disasm.contains("func 0:"));
+        assert!(disasm.contains("SYS_WRITE"));
+        assert_eq!(diff(&module, &module), "");
+        let other = compile(&WAT.replace("i32.const 0\n    call", "i32.const 1\n    call"));
+        assert!(!diff(&module, &other).is_empty());
+    }
+}
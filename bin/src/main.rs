@@ -2,194 +2,231 @@
 
 extern crate core;
 
-use crate::types::FileFormat;
-use clap::Parser;
-use fluentbase_core::helpers::rwasm_module;
+use crate::{source_map::SourceMap, types::FileFormat};
+use clap::{Parser, Subcommand};
+use fluentbase_types::{create_sovereign_import_linker, SysFuncIdx, STATE_DEPLOY, STATE_MAIN};
 use log::debug;
-use rwasm::rwasm::{instruction::INSTRUCTION_SIZE_BYTES, BinaryFormat};
+use rwasm::{
+    engine::{bytecode::Instruction, RwasmConfig, StateRouterConfig},
+    rwasm::{instruction::INSTRUCTION_SIZE_BYTES, BinaryFormat, RwasmModule},
+    Config,
+    FuelConsumptionMode,
+};
 use std::{fs, path::Path};
 
+mod inspect;
+mod run;
+mod source_map;
 mod types;
 
 /// Command line utility which takes input WAT/WASM file and converts it into RWASM
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
 struct Args {
-    #[arg(long)]
-    file_in_path: String,
+    #[command(subcommand)]
+    command: Option<Command>,
 
-    #[arg(long, default_value = "")]
-    rwasm_file_out_path: String,
+    #[arg(long, required = true)]
+    file_in_path: Option<String>,
 
     #[arg(long, default_value = "")]
-    rs_file_out_path: String,
+    rwasm_file_out_path: String,
 
     #[arg(long, default_value_t = false)]
     print_rwasm_bytes: bool,
 
+    /// Generate a JSON source map (WASM function to rWASM instruction range), only WASM and WAT
+    /// inputs are supported
     #[arg(long, default_value_t = false)]
     gen_source_map: bool,
 
-    #[arg(long, default_value_t = false)]
-    do_not_translate_sections: bool,
-
-    #[arg(long, default_value_t = false)]
-    skip_type_check: bool,
+    /// Source map output path, `<file_in_name>_source_map.json` in the output dir by default
+    #[arg(long, default_value = "")]
+    source_map_file_out_path: String,
 
+    /// Inject fuel consumption instructions
     #[arg(long, default_value_t = false)]
     inject_fuel: bool,
 
+    /// Don't generate the state router, the entry function is called for every state
     #[arg(long, default_value_t = false)]
     no_router: bool,
 
+    /// Exported function used as an entrypoint when the router is disabled
     #[arg(long, default_value = "")]
     entry_fn_name: String,
 
-    #[arg(long, default_value = "")]
-    restricted_fn_names: String,
-
-    #[arg(long, default_value = "")]
-    restricted_fn_name_prefixes: String,
-
     #[arg(long, default_value_t = false)]
     entry_fn_name_matches_file_in_name: bool,
 
-    #[arg(long, default_value_t = false)]
-    debug: bool,
-
-    #[arg(long, default_value_t = false)]
-    no_magic_prefix: bool,
-
-    #[arg(long, default_value_t = false)]
-    inject_init_bytecode: bool,
+    /// Comma separated `name=state` pairs the state router dispatches to (`deploy` and `main` by
+    /// default)
+    #[arg(long, default_value = "")]
+    router_states: String,
+}
 
-    #[arg(long, default_value_t = false)]
-    retranslate_main: bool,
+/// Inspection commands, accept `.wat`, `.wasm` and `.rwasm` files
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Print rWASM instructions with their offsets
+    Disasm { file_in_path: String },
+    /// Print section summaries, import usage, state router entries and instruction counts
+    Info { file_in_path: String },
+    /// Print differences between two modules
+    Diff {
+        left_file_in_path: String,
+        right_file_in_path: String,
+    },
+    /// Execute rWASM bytecode with a mock context and print the execution result
+    Run {
+        /// `.rwasm` file, `.wat`/`.wasm` files are compiled first
+        #[arg(long)]
+        rwasm: String,
+        /// Hex string or path to a file with raw input bytes
+        #[arg(long, default_value = "")]
+        input: String,
+        #[arg(long, value_enum, default_value_t = run::RunState::Main)]
+        state: run::RunState,
+        #[arg(long, default_value_t = 10_000_000)]
+        fuel: u64,
+        /// JSON file with the mock execution context
+        #[arg(long)]
+        context: Option<String>,
+        /// Directory to load the trie state from and save it to, in-memory trie if not set
+        #[arg(long)]
+        trie_snapshot: Option<String>,
+    },
 }
 
 fn main() {
     let args = Args::parse();
-    let file_in_format: FileFormat;
-    if args.file_in_path.ends_with(".wat") {
-        file_in_format = FileFormat::Wat;
-    } else if args.file_in_path.ends_with(".wasm") {
-        file_in_format = FileFormat::Wasm;
+    match &args.command {
+        Some(Command::Disasm { file_in_path }) => {
+            print!(
+                "{}",
+                inspect::disasm(&inspect::load_rwasm_module(file_in_path))
+            );
+        }
+        Some(Command::Info { file_in_path }) => {
+            let rwasm_module = inspect::load_rwasm_module(file_in_path);
+            print!("{}", inspect::ModuleInfo::new(&rwasm_module).render());
+        }
+        Some(Command::Diff {
+            left_file_in_path,
+            right_file_in_path,
+        }) => {
+            print!(
+                "{}",
+                inspect::diff(
+                    &inspect::load_rwasm_module(left_file_in_path),
+                    &inspect::load_rwasm_module(right_file_in_path)
+                )
+            );
+        }
+        Some(Command::Run {
+            rwasm,
+            input,
+            state,
+            fuel,
+            context,
+            trie_snapshot,
+        }) => {
+            let context = context
+                .as_ref()
+                .map(|path| serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap())
+                .unwrap_or_default();
+            let (trie_db, state_root) = match trie_snapshot {
+                Some(dir) => run::SnapshotTrieDb::load(Path::new(dir)),
+                None => Default::default(),
+            };
+            let result = run::run(
+                run::load_rwasm_bytecode(rwasm),
+                run::parse_input(input),
+                *state,
+                *fuel,
+                &context,
+                trie_db.clone(),
+                state_root,
+            );
+            if let Some(dir) = trie_snapshot {
+                trie_db.save(Path::new(dir), &result.state_root);
+            }
+            print!("{}", result.render());
+        }
+        None => compile(args),
+    }
+}
+
+fn parse_router_states(router_states: &str) -> Box<[(String, u32)]> {
+    if router_states.is_empty() {
+        return Box::new([
+            ("deploy".to_string(), STATE_DEPLOY),
+            ("main".to_string(), STATE_MAIN),
+        ]);
+    }
+    router_states
+        .split(',')
+        .map(|v| {
+            let (name, state) = v
+                .split_once('=')
+                .expect("router state must be in the 'name=state' format");
+            (name.to_string(), state.parse::<u32>().unwrap())
+        })
+        .collect()
+}
+
+fn compile_config(args: &Args, file_in_name: &str) -> Config {
+    let entry_fn_name = if args.entry_fn_name_matches_file_in_name {
+        file_in_name.to_string()
+    } else {
+        args.entry_fn_name.clone()
+    };
+    let state_router = if args.no_router {
+        None
     } else {
-        panic!("only '.wat' and '.wasm' formats are supported")
+        Some(StateRouterConfig {
+            states: parse_router_states(&args.router_states),
+            opcode: Instruction::Call(SysFuncIdx::SYS_STATE.into()),
+        })
+    };
+    let mut config = RwasmModule::default_config(None);
+    config.floats(false);
+    config.rwasm_config(RwasmConfig {
+        state_router,
+        entrypoint_name: (!entry_fn_name.is_empty()).then_some(entry_fn_name),
+        import_linker: Some(create_sovereign_import_linker()),
+        wrap_import_functions: true,
+    });
+    if args.inject_fuel {
+        config.fuel_consumption_mode(FuelConsumptionMode::Eager);
+        config.consume_fuel(true);
     }
+    config
+}
 
-    let file_bytes = fs::read(args.file_in_path.clone()).unwrap();
+fn compile(args: Args) {
+    let args_file_in_path = args.file_in_path.clone().unwrap();
+    let file_bytes = fs::read(&args_file_in_path).unwrap();
     let wasm_binary: Vec<u8>;
-    match file_in_format {
+    match FileFormat::from_path(&args_file_in_path) {
         FileFormat::Wat => {
             wasm_binary = wat::parse_bytes(&file_bytes).unwrap().to_vec();
         }
         FileFormat::Wasm => {
             wasm_binary = file_bytes;
         }
+        FileFormat::Rwasm => panic!("only '.wat' and '.wasm' formats can be compiled"),
     }
 
-    let rwasm_module = rwasm_module(&wasm_binary).unwrap();
-
-    let file_in_path = Path::new(&args.file_in_path);
+    let file_in_path = Path::new(&args_file_in_path);
     let file_in_name = file_in_path.file_stem().unwrap().to_str().unwrap();
-    // let mut fn_idx = 0;
-    // let entry_fn_name = if args.entry_fn_name_matches_file_in_name {
-    //     file_in_name.to_string()
-    // } else {
-    //     args.entry_fn_name
-    // };
-    // if entry_fn_name != "" {
-    //     let fn_name = Box::new(entry_fn_name);
-    //     fn_idx = compiler
-    //         .resolve_func_index(&FuncOrExport::Export(Box::leak(fn_name)))
-    //         .unwrap()
-    //         .unwrap();
-    // };
-    // if args.retranslate_main {
-    //     compiler.translate(FuncOrExport::Func(fn_idx)).unwrap();
-    // }
-    // let func_source_maps = compiler.build_source_map();
-    // let entry_point_fn = &func_source_maps[0];
-    // debug!(
-    //     "zero_fn_source_map name '{}' index '{}' pos '{}' len '{}'",
-    //     entry_point_fn.fn_name,
-    //     entry_point_fn.fn_index,
-    //     entry_point_fn.position,
-    //     entry_point_fn.length
-    // );
-    // let mut as_rust_vec: Vec<String> = vec![];
-    // let restricted_fn_names = args
-    //     .restricted_fn_names
-    //     .split(",")
-    //     .collect::<Vec<&str>>()
-    //     .iter()
-    //     .map(|v| v.to_lowercase())
-    //     .collect::<Vec<_>>();
-    // let restricted_fn_name_prefixes = args
-    //     .restricted_fn_name_prefixes
-    //     .split(",")
-    //     .filter(|v| !v.is_empty())
-    //     .collect::<Vec<&str>>()
-    //     .iter()
-    //     .map(|v| v.to_lowercase())
-    //     .collect::<Vec<_>>();
-    // debug!("restricted_fn_names {:?}", restricted_fn_names.as_slice());
-    // debug!(
-    //     "restricted_fn_name_prefixes {:?}",
-    //     restricted_fn_name_prefixes.as_slice()
-    // );
-    // const FUNC_SYSTEM_PREFIX: &'static str = "$__";
-    // for func_source_map in &func_source_maps {
-    //     debug!("func_source_map '{:?}'", func_source_map);
-    //     let fn_name = func_source_map.fn_name.as_str();
-    //     let fn_beginning = func_source_map.position;
-    //     let fn_length = func_source_map.length;
-    //     if fn_name == FUNC_SOURCE_MAP_ENTRYPOINT_NAME {
-    //         let opcode = FUNC_SOURCE_MAP_ENTRYPOINT_IDX;
-    //         as_rust_vec.push(format!("({opcode}, {fn_beginning}, {fn_length})"));
-    //     } else {
-    //         let mut is_restricted = fn_name.starts_with(FUNC_SYSTEM_PREFIX)
-    //             || restricted_fn_names.contains(&fn_name.to_string());
-    //         if !is_restricted && !restricted_fn_name_prefixes.is_empty() {
-    //             is_restricted = restricted_fn_name_prefixes
-    //                 .iter()
-    //                 .find(|&p| fn_name.starts_with(p))
-    //                 .is_some();
-    //         };
-    //         if !is_restricted {
-    //             let fn_name = fn_name.to_uppercase();
-    //             let fn_name_split = fn_name.split("_").collect::<Vec<_>>();
-    //             let opcode_name = fn_name_split[fn_name_split.len() - 1];
-    //             as_rust_vec.push(format!(
-    //                 "(opcode::{opcode_name} as u32, {fn_beginning}, {fn_length})"
-    //             ));
-    //         }
-    //     }
-    // }
-    // let rs_str = format!("[\n    {}\n]", as_rust_vec.join(",\n    "));
+    let rwasm_module =
+        RwasmModule::compile_with_config(&wasm_binary, &compile_config(&args, file_in_name))
+            .unwrap();
 
     let mut rwasm_binary = Vec::new();
     rwasm_module.write_binary_to_vec(&mut rwasm_binary).unwrap();
-    // let init_bytecode_instruction_to_cut = 4; // redundant instruction inside init bytecode
-    // let init_bytecode = if args.inject_init_bytecode {
-    //     rwasm_binary[entry_point_fn.position as usize * INSTRUCTION_SIZE_BYTES
-    //         ..(entry_point_fn.position + entry_point_fn.length) as usize *
-    // INSTRUCTION_SIZE_BYTES]         .to_vec()
-    // } else {
-    //     vec![]
-    // };
-    // debug!(
-    //     "extending rwasm_binary (byte len {}, instruction len {}) with init_bytecode (instruction
-    // position {} len {} fact len {})",     rwasm_binary.len(),
-    //     rwasm_binary.len() / INSTRUCTION_SIZE_BYTES,
-    //     entry_point_fn.position,
-    //     entry_point_fn.length,
-    //     init_bytecode.len() / INSTRUCTION_SIZE_BYTES
-    // );
-    // if args.inject_init_bytecode {
-    //     rwasm_binary.extend(&init_bytecode);
-    // }
     let rwasm_file_out_path;
     let oud_dir_path = file_in_path.parent().unwrap().to_str().unwrap();
     if args.rwasm_file_out_path != "" {
@@ -211,19 +248,20 @@ fn main() {
     }
     fs::write(rwasm_file_out_path, rwasm_binary).unwrap();
 
-    // if args.gen_source_map {
-    //     let rs_source_map_file_out_path;
-    //     if args.rs_file_out_path != "" {
-    //         rs_source_map_file_out_path = args.rs_file_out_path;
-    //     } else {
-    //         rs_source_map_file_out_path = format!(
-    //             "{}/{}",
-    //             oud_dir_path,
-    //             format!("{}{}", file_in_name, "_source_map.rs")
-    //         );
-    //     }
-    //     fs::write(rs_source_map_file_out_path, rs_str).unwrap();
-    // }
+    if args.gen_source_map {
+        let source_map_file_out_path;
+        if args.source_map_file_out_path != "" {
+            source_map_file_out_path = args.source_map_file_out_path;
+        } else {
+            source_map_file_out_path = format!(
+                "{}/{}",
+                oud_dir_path,
+                format!("{}{}", file_in_name, types::SOURCE_MAP_OUT_FILE_EXT)
+            );
+        }
+        let source_map = SourceMap::from_rwasm_module(&rwasm_module);
+        fs::write(source_map_file_out_path, source_map.to_json()).unwrap();
+    }
 }
 
 #[ctor::ctor]
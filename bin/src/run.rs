@@ -0,0 +1,315 @@
+use crate::types::FileFormat;
+use alloy_primitives::{Address, B256, U256};
+use fluentbase_codec::Encoder;
+use fluentbase_core::helpers::wasm2rwasm;
+use fluentbase_runtime::{
+    types::TrieDb,
+    zktrie::ZkTrieStateDb,
+    IJournaledTrie,
+    JournalCheckpoint,
+    JournalLog,
+    JournaledTrie,
+    Runtime,
+    RuntimeContext,
+};
+use fluentbase_sdk::evm::ContractInput;
+use fluentbase_types::{Bytes, FuelSchedule, STATE_DEPLOY, STATE_MAIN};
+use serde::{Deserialize, Serialize};
+use std::{cell::RefCell, collections::BTreeMap, fs, path::Path, rc::Rc};
+
+const SNAPSHOT_FILE_NAME: &str = "snapshot.json";
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RunState {
+    Deploy,
+    Main,
+}
+
+impl RunState {
+    fn to_u32(self) -> u32 {
+        match self {
+            RunState::Deploy => STATE_DEPLOY,
+            RunState::Main => STATE_MAIN,
+        }
+    }
+}
+
+/// Mock execution context, every missing field keeps the `ContractInput` default
+#[derive(Deserialize, Default, Debug)]
+#[serde(default)]
+pub(crate) struct RunContext {
+    chain_id: u64,
+    /// Spec id of revm, the default spec is used if it isn't set
+    spec_id: Option<u8>,
+    /// Amount of fuel per unit of gas, zero means the default ratio
+    fuel_per_gas: u64,
+    gas_limit: u64,
+    address: Address,
+    caller: Address,
+    value: U256,
+    is_static: bool,
+    block_coinbase: Address,
+    block_timestamp: u64,
+    block_number: u64,
+    block_gas_limit: u64,
+    block_base_fee: U256,
+    block_prevrandao: B256,
+    tx_caller: Address,
+    tx_gas_price: U256,
+    tx_gas_limit: u64,
+    tx_nonce: u64,
+}
+
+impl RunContext {
+    fn contract_input(&self, input: Vec<u8>) -> ContractInput {
+        ContractInput {
+            env_chain_id: self.chain_id,
+            env_spec_id: self.spec_id,
+            env_fuel_per_gas: self.fuel_per_gas,
+            contract_gas_limit: self.gas_limit,
+            contract_address: self.address,
+            contract_caller: self.caller,
+            contract_input: Bytes::from(input),
+            contract_value: self.value,
+            contract_is_static: self.is_static,
+            block_coinbase: self.block_coinbase,
+            block_timestamp: self.block_timestamp,
+            block_number: self.block_number,
+            block_gas_limit: self.block_gas_limit,
+            block_base_fee: self.block_base_fee,
+            block_prevrandao: self.block_prevrandao,
+            tx_caller: self.tx_caller,
+            tx_gas_price: self.tx_gas_price,
+            tx_gas_limit: self.tx_gas_limit,
+            tx_nonce: self.tx_nonce,
+            ..Default::default()
+        }
+    }
+}
+
+/// Trie nodes and preimages kept in memory, clones share the same maps so the state can be saved
+/// after the runtime takes ownership of the trie
+#[derive(Default, Clone)]
+pub(crate) struct SnapshotTrieDb {
+    nodes: Rc<RefCell<BTreeMap<Vec<u8>, Vec<u8>>>>,
+    preimages: Rc<RefCell<BTreeMap<Vec<u8>, Vec<u8>>>>,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct Snapshot {
+    root: String,
+    nodes: BTreeMap<String, String>,
+    preimages: BTreeMap<String, String>,
+}
+
+impl SnapshotTrieDb {
+    /// Loads the snapshot from `dir` and returns it with the saved state root, or an empty trie if
+    /// there is no snapshot yet
+    pub(crate) fn load(dir: &Path) -> (Self, [u8; 32]) {
+        let db = Self::default();
+        let mut root = [0u8; 32];
+        let Ok(json) = fs::read_to_string(dir.join(SNAPSHOT_FILE_NAME)) else {
+            return (db, root);
+        };
+        let snapshot: Snapshot = serde_json::from_str(&json).unwrap();
+        root.copy_from_slice(&hex::decode(snapshot.root).unwrap());
+        let decode = |map: BTreeMap<String, String>| {
+            map.into_iter()
+                .map(|(k, v)| (hex::decode(k).unwrap(), hex::decode(v).unwrap()))
+                .collect::<BTreeMap<_, _>>()
+        };
+        *db.nodes.borrow_mut() = decode(snapshot.nodes);
+        *db.preimages.borrow_mut() = decode(snapshot.preimages);
+        (db, root)
+    }
+
+    pub(crate) fn save(&self, dir: &Path, root: &[u8; 32]) {
+        let encode = |map: &BTreeMap<Vec<u8>, Vec<u8>>| {
+            map.iter()
+                .map(|(k, v)| (hex::encode(k), hex::encode(v)))
+                .collect::<BTreeMap<_, _>>()
+        };
+        let snapshot = Snapshot {
+            root: hex::encode(root),
+            nodes: encode(&self.nodes.borrow()),
+            preimages: encode(&self.preimages.borrow()),
+        };
+        fs::create_dir_all(dir).unwrap();
+        fs::write(
+            dir.join(SNAPSHOT_FILE_NAME),
+            serde_json::to_string_pretty(&snapshot).unwrap(),
+        )
+        .unwrap();
+    }
+}
+
+impl TrieDb for SnapshotTrieDb {
+    fn get_node(&mut self, key: &[u8]) -> Option<Bytes> {
+        self.nodes.borrow().get(key).cloned().map(Bytes::from)
+    }
+
+    fn update_node(&mut self, key: &[u8], value: Bytes) {
+        self.nodes.borrow_mut().insert(key.to_vec(), value.to_vec());
+    }
+
+    fn get_preimage(&mut self, key: &[u8]) -> Option<Bytes> {
+        self.preimages.borrow().get(key).cloned().map(Bytes::from)
+    }
+
+    fn update_preimage(&mut self, key: &[u8], value: Bytes) {
+        self.preimages
+            .borrow_mut()
+            .insert(key.to_vec(), value.to_vec());
+    }
+}
+
+pub(crate) struct RunResult {
+    pub(crate) exit_code: i32,
+    pub(crate) output: Vec<u8>,
+    pub(crate) fuel_consumed: Option<u64>,
+    pub(crate) logs: Vec<JournalLog>,
+    pub(crate) state_root: [u8; 32],
+}
+
+impl RunResult {
+    pub(crate) fn render(&self) -> String {
+        let mut result = String::new();
+        result.push_str(&format!("exit code: {}\n", self.exit_code));
+        result.push_str(&format!("output: 0x{}\n", hex::encode(&self.output)));
+        match self.fuel_consumed {
+            Some(fuel) => result.push_str(&format!("fuel consumed: {}\n", fuel)),
+            None => result.push_str("fuel consumed: unknown\n"),
+        }
+        result.push_str("logs:\n");
+        for log in self.logs.iter() {
+            result.push_str(&format!("  address: {}\n", log.address));
+            for topic in log.topics.iter() {
+                result.push_str(&format!("    topic: {}\n", topic));
+            }
+            result.push_str(&format!("    data: {}\n", log.data));
+        }
+        result.push_str(&format!("state root: 0x{}\n", hex::encode(self.state_root)));
+        result
+    }
+}
+
+/// Reads rWASM bytecode from a `.rwasm` file or compiles a `.wat`/`.wasm` one.
+pub(crate) fn load_rwasm_bytecode(file_in_path: &str) -> Vec<u8> {
+    let file_bytes = fs::read(file_in_path).unwrap();
+    match FileFormat::from_path(file_in_path) {
+        FileFormat::Rwasm => file_bytes,
+        FileFormat::Wat => wasm2rwasm(&wat::parse_bytes(&file_bytes).unwrap()).unwrap(),
+        FileFormat::Wasm => wasm2rwasm(&file_bytes).unwrap(),
+    }
+}
+
+/// Input is either a path to a file with raw bytes or a hex string (`0x` prefix is optional).
+pub(crate) fn parse_input(input: &str) -> Vec<u8> {
+    if Path::new(input).is_file() {
+        return fs::read(input).unwrap();
+    }
+    hex::decode(input.trim_start_matches("0x")).expect("input must be a file path or a hex string")
+}
+
+/// Executes rWASM bytecode with the input wrapped into a `ContractInput` built from the mock
+/// context. Changes are committed into `trie_db` only if execution succeeds.
+pub(crate) fn run(
+    rwasm_bytecode: Vec<u8>,
+    input: Vec<u8>,
+    state: RunState,
+    fuel_limit: u64,
+    context: &RunContext,
+    trie_db: SnapshotTrieDb,
+    state_root: [u8; 32],
+) -> RunResult {
+    let jzkt = Rc::new(RefCell::new(JournaledTrie::new(ZkTrieStateDb::new_opened(
+        trie_db,
+        &state_root,
+    ))));
+    let contract_input = context.contract_input(input).encode_to_vec(0);
+    let mut ctx = RuntimeContext::<()>::new(rwasm_bytecode);
+    ctx.with_state(state.to_u32())
+        .with_fuel_limit(fuel_limit)
+        .with_input(contract_input)
+        .with_is_static(context.is_static)
+        .with_jzkt(jzkt.clone())
+        .with_fuel_schedule(Rc::new(
+            FuelSchedule::default().with_fuel_per_gas(context.fuel_per_gas),
+        ))
+        .with_catch_trap(true);
+    let import_linker = Runtime::<()>::new_sovereign_linker();
+    let execution_result = Runtime::<()>::run_with_context(ctx, import_linker).unwrap();
+    let exit_code = execution_result.data().exit_code();
+
+    let mut jzkt = jzkt.borrow_mut();
+    let (state_root, logs) = if exit_code != 0 {
+        (state_root, vec![])
+    } else if jzkt.checkpoint().state() == 0 {
+        (
+            jzkt.compute_root(),
+            jzkt.logs(JournalCheckpoint(0, 0)).to_vec(),
+        )
+    } else {
+        jzkt.commit().unwrap()
+    };
+    RunResult {
+        exit_code,
+        output: execution_result.data().output().clone(),
+        fuel_consumed: execution_result.fuel_consumed(),
+        logs,
+        state_root,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_writes_output() {
+        let wasm_binary = wat::parse_str(
+            r#"
+(module
+  (type (;0;) (func (param i32 i32)))
+  (import "fluentbase_v1alpha" "_sys_write" (func $_sys_write (type 0)))
+  (func $main
+    i32.const 0
+    i32.const 5
+    call $_sys_write)
+  (func $deploy)
+  (memory 1)
+  (data (i32.const 0) "Hello")
+  (export "main" (func $main))
+  (export "deploy" (func $deploy)))
+            "#,
+        )
+        .unwrap();
+        let rwasm_bytecode = wasm2rwasm(&wasm_binary).unwrap();
+        let result = run(
+            rwasm_bytecode,
+            vec![],
+            RunState::Main,
+            100_000,
+            &RunContext::default(),
+            SnapshotTrieDb::default(),
+            [0u8; 32],
+        );
+        assert_eq!(result.exit_code, 0);
+        assert_eq!(result.output, "Hello".as_bytes().to_vec());
+        assert!(result.render().contains("output: 0x48656c6c6f"));
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let dir = std::env::temp_dir().join("rwasmc-snapshot-test");
+        let mut db = SnapshotTrieDb::default();
+        db.update_node(&[1, 2], Bytes::from(vec![3, 4]));
+        db.update_preimage(&[5], Bytes::from(vec![6]));
+        db.save(&dir, &[7u8; 32]);
+        let (mut loaded, root) = SnapshotTrieDb::load(&dir);
+        assert_eq!(root, [7u8; 32]);
+        assert_eq!(loaded.get_node(&[1, 2]), Some(Bytes::from(vec![3, 4])));
+        assert_eq!(loaded.get_preimage(&[5]), Some(Bytes::from(vec![6])));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,73 @@
+use rwasm::rwasm::RwasmModule;
+use serde::Serialize;
+
+/// Range of rWASM instructions (`begin` inclusive, `end` exclusive) produced for one WASM function.
+#[derive(Serialize, Debug, Clone, PartialEq, Eq)]
+pub(crate) struct FuncSourceMap {
+    pub(crate) fn_index: u32,
+    pub(crate) begin: u32,
+    pub(crate) end: u32,
+}
+
+/// JSON source map that attributes rWASM instructions back to the WASM functions they were
+/// compiled from.
+///
+/// Only WASM (and WAT) inputs are supported, EVM pc mapping requires the EVM to rWASM translator
+/// that isn't a part of the workspace.
+#[derive(Serialize, Debug, Default, Clone, PartialEq, Eq)]
+pub(crate) struct SourceMap {
+    pub(crate) functions: Vec<FuncSourceMap>,
+}
+
+impl SourceMap {
+    pub(crate) fn from_rwasm_module(rwasm_module: &RwasmModule) -> Self {
+        let mut functions = Vec::with_capacity(rwasm_module.func_section.len());
+        let mut begin = 0u32;
+        for (fn_index, fn_length) in rwasm_module.func_section.iter().enumerate() {
+            functions.push(FuncSourceMap {
+                fn_index: fn_index as u32,
+                begin,
+                end: begin + *fn_length,
+            });
+            begin += *fn_length;
+        }
+        Self { functions }
+    }
+
+    pub(crate) fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fluentbase_core::helpers::rwasm_module;
+
+    #[test]
+    fn test_functions_cover_code_section() {
+        let wasm_binary = wat::parse_str(
+            r#"
+(module
+  (func $main
+    i32.const 1
+    drop)
+  (func $deploy)
+  (export "main" (func $main))
+  (export "deploy" (func $deploy)))
+            "#,
+        )
+        .unwrap();
+        let rwasm_module = rwasm_module(&wasm_binary).unwrap();
+        let source_map = SourceMap::from_rwasm_module(&rwasm_module);
+        assert!(!source_map.functions.is_empty());
+        for pair in source_map.functions.windows(2) {
+            assert_eq!(pair[0].end, pair[1].begin);
+        }
+        assert_eq!(
+            source_map.functions.last().unwrap().end as usize,
+            rwasm_module.code_section.len() as usize
+        );
+        assert!(source_map.to_json().contains("\"functions\""));
+    }
+}
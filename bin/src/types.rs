@@ -1,5 +1,21 @@
 pub(crate) const RWASM_OUT_FILE_EXT: &str = ".rwasm";
+pub(crate) const SOURCE_MAP_OUT_FILE_EXT: &str = "_source_map.json";
 pub(crate) enum FileFormat {
     Wat,
     Wasm,
+    Rwasm,
+}
+
+impl FileFormat {
+    pub(crate) fn from_path(path: &str) -> Self {
+        if path.ends_with(".wat") {
+            FileFormat::Wat
+        } else if path.ends_with(".wasm") {
+            FileFormat::Wasm
+        } else if path.ends_with(RWASM_OUT_FILE_EXT) {
+            FileFormat::Rwasm
+        } else {
+            panic!("only '.wat', '.wasm' and '.rwasm' formats are supported")
+        }
+    }
 }
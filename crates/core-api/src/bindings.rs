@@ -26,6 +26,21 @@ derive_helpers_and_structs! {
             args_size: u32,
             gas_limit: u32,
         ) -> *mut u8; // ret_offset
+
+        fn _evm_delegatecall(
+            callee_address20_offset: *const u8,
+            args_offset: *const u8,
+            args_size: u32,
+            gas_limit: u32,
+        ) -> *mut u8; // ret_offset
+
+        fn _evm_callcode(
+            callee_address20_offset: *const u8,
+            value32_offset: *const u8,
+            args_offset: *const u8,
+            args_size: u32,
+            gas_limit: u32,
+        ) -> *mut u8; // ret_offset
     }
 }
 
@@ -53,5 +68,20 @@ derive_helpers_and_structs! {
             args_size: u32,
             gas_limit: u32,
         );
+
+        fn _wasm_delegatecall(
+            callee_address20_offset: *const u8,
+            args_offset: *const u8,
+            args_size: u32,
+            gas_limit: u32,
+        );
+
+        fn _wasm_callcode(
+            callee_address20_offset: *const u8,
+            value32_offset: *const u8,
+            args_offset: *const u8,
+            args_size: u32,
+            gas_limit: u32,
+        );
     }
 }
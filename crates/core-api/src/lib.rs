@@ -3,7 +3,6 @@
 
 extern crate alloc;
 extern crate core;
-extern crate fluentbase_sdk;
 
 pub use fluentbase_types::ExitCode;
 
@@ -2,16 +2,21 @@ use crate::account_types::{
     AccountCheckpoint,
     AccountFields,
     JZKT_ACCOUNT_BALANCE_FIELD,
+    JZKT_ACCOUNT_FIELDS_COUNT,
     JZKT_ACCOUNT_NONCE_FIELD,
     JZKT_ACCOUNT_RWASM_BYTECODE_HASH_FIELD,
     JZKT_ACCOUNT_RWASM_BYTECODE_SIZE_FIELD,
     JZKT_ACCOUNT_SOURCE_BYTECODE_HASH_FIELD,
     JZKT_ACCOUNT_SOURCE_BYTECODE_SIZE_FIELD,
     JZKT_COMPRESSION_FLAGS,
+    JZKT_IMMUTABLE_DATA_COMPRESSION_FLAGS,
+    JZKT_IMMUTABLE_DATA_FIELDS_COUNT,
+    JZKT_IMMUTABLE_DATA_HASH_FIELD,
+    JZKT_IMMUTABLE_DATA_SIZE_FIELD,
 };
 use alloc::vec;
 use byteorder::{ByteOrder, LittleEndian};
-use fluentbase_sdk::{Bytes32, LowLevelAPI, LowLevelSDK};
+use fluentbase_sdk::{evm::immutable_data_key, Bytes32, LowLevelAPI, LowLevelSDK};
 use fluentbase_types::{Address, Bytes, ExitCode, B256, KECCAK_EMPTY, POSEIDON_EMPTY, U256};
 
 #[derive(Debug, Clone)]
@@ -47,6 +52,28 @@ impl Account {
         }
     }
 
+    /// Restores account from the raw JZKT fields, it's the inverse of [`Account::get_fields`]
+    pub fn new_from_fields(address: &Address, fields: &[Bytes32]) -> Self {
+        let mut result = Self::new(address);
+        assert_eq!(
+            fields.len(),
+            JZKT_ACCOUNT_FIELDS_COUNT as usize,
+            "account must have {} fields",
+            JZKT_ACCOUNT_FIELDS_COUNT
+        );
+        result.balance = U256::from_le_slice(&fields[JZKT_ACCOUNT_BALANCE_FIELD as usize]);
+        result.nonce = LittleEndian::read_u64(&fields[JZKT_ACCOUNT_NONCE_FIELD as usize]);
+        result.source_bytecode_size =
+            LittleEndian::read_u64(&fields[JZKT_ACCOUNT_SOURCE_BYTECODE_SIZE_FIELD as usize]);
+        result.source_bytecode_hash =
+            B256::from_slice(&fields[JZKT_ACCOUNT_SOURCE_BYTECODE_HASH_FIELD as usize]);
+        result.rwasm_bytecode_size =
+            LittleEndian::read_u64(&fields[JZKT_ACCOUNT_RWASM_BYTECODE_SIZE_FIELD as usize]);
+        result.rwasm_bytecode_hash =
+            B256::from_slice(&fields[JZKT_ACCOUNT_RWASM_BYTECODE_HASH_FIELD as usize]);
+        result
+    }
+
     pub fn new_from_jzkt(address: &Address) -> Self {
         let mut result = Self::new(address);
         let address_word = address.into_word();
@@ -247,6 +274,35 @@ impl Account {
         assert!(r, "account update_bytecode failed");
     }
 
+    /// Stores data returned by the deploy function, it can't be changed after deployment
+    pub fn update_immutable_data(&self, data: &Bytes) {
+        let key = immutable_data_key(&self.address);
+        let mut fields = [Bytes32::default(); JZKT_IMMUTABLE_DATA_FIELDS_COUNT as usize];
+        LittleEndian::write_u64(
+            &mut fields[JZKT_IMMUTABLE_DATA_SIZE_FIELD as usize][..],
+            data.len() as u64,
+        );
+        LowLevelSDK::crypto_keccak256(
+            data.as_ptr(),
+            data.len() as u32,
+            fields[JZKT_IMMUTABLE_DATA_HASH_FIELD as usize].as_mut_ptr(),
+        );
+        LowLevelSDK::jzkt_update(
+            key.as_ptr(),
+            JZKT_IMMUTABLE_DATA_COMPRESSION_FLAGS,
+            fields.as_ptr(),
+            32 * fields.len() as u32,
+        );
+        // make sure preimage of this hash is stored
+        let r = LowLevelSDK::jzkt_update_preimage(
+            key.as_ptr(),
+            JZKT_IMMUTABLE_DATA_HASH_FIELD,
+            data.as_ptr(),
+            data.len() as u32,
+        );
+        assert!(r, "account update_immutable_data failed");
+    }
+
     pub fn checkpoint() -> AccountCheckpoint {
         LowLevelSDK::jzkt_checkpoint()
     }
@@ -1,24 +1,20 @@
 use fluentbase_sdk::Bytes32;
+pub use fluentbase_types::JZKT_COMPRESSION_FLAGS;
+pub(crate) use fluentbase_types::{
+    JZKT_ACCOUNT_BALANCE_FIELD,
+    JZKT_ACCOUNT_FIELDS_COUNT,
+    JZKT_ACCOUNT_NONCE_FIELD,
+    JZKT_ACCOUNT_RWASM_BYTECODE_HASH_FIELD,
+    JZKT_ACCOUNT_RWASM_BYTECODE_SIZE_FIELD,
+    JZKT_ACCOUNT_SOURCE_BYTECODE_HASH_FIELD,
+    JZKT_ACCOUNT_SOURCE_BYTECODE_SIZE_FIELD,
+    JZKT_IMMUTABLE_DATA_FIELDS_COUNT,
+    JZKT_IMMUTABLE_DATA_HASH_FIELD,
+    JZKT_IMMUTABLE_DATA_SIZE_FIELD,
+};
 
-/// Number of fields
-pub(crate) const JZKT_ACCOUNT_FIELDS_COUNT: u32 = 6;
-
-pub(crate) const JZKT_ACCOUNT_BALANCE_FIELD: u32 = 0;
-pub(crate) const JZKT_ACCOUNT_NONCE_FIELD: u32 = 1;
-pub(crate) const JZKT_ACCOUNT_SOURCE_BYTECODE_SIZE_FIELD: u32 = 2;
-pub(crate) const JZKT_ACCOUNT_SOURCE_BYTECODE_HASH_FIELD: u32 = 3;
-pub(crate) const JZKT_ACCOUNT_RWASM_BYTECODE_SIZE_FIELD: u32 = 4;
-pub(crate) const JZKT_ACCOUNT_RWASM_BYTECODE_HASH_FIELD: u32 = 5;
-
-/// Compression flags for upper fields.
-///
-/// We compress following fields:
-/// - balance (0) because of balance overflow
-/// - source code hash (3) because its keccak256
-///
-/// Mask is: 0b00001001
-pub const JZKT_COMPRESSION_FLAGS: u32 =
-    (1 << JZKT_ACCOUNT_BALANCE_FIELD) + (1 << JZKT_ACCOUNT_SOURCE_BYTECODE_HASH_FIELD);
+/// Immutable data hash is keccak256 so it's compressed as well
+pub const JZKT_IMMUTABLE_DATA_COMPRESSION_FLAGS: u32 = 1 << JZKT_IMMUTABLE_DATA_HASH_FIELD;
 
 /// EIP-170: Contract code size limit
 ///
@@ -1,7 +1,5 @@
 use fluentbase_types::{address, Address};
-
-pub const ECL_CONTRACT_ADDRESS: Address = address!("5200000000000000000000000000000000000001");
-pub const WCL_CONTRACT_ADDRESS: Address = address!("5200000000000000000000000000000000000002");
+pub use fluentbase_types::{ECL_CONTRACT_ADDRESS, WCL_CONTRACT_ADDRESS};
 
 // precompiles
 pub const PRECOMPILE_BLAKE2_ADDRESS: Address = address!("0000000000000000000000000000000000000001");
@@ -1,9 +1,22 @@
-use crate::evm::{call::_evm_call, create::_evm_create, create2::_evm_create2};
+use crate::evm::{
+    call::_evm_call,
+    callcode::_evm_callcode,
+    create::_evm_create,
+    create2::_evm_create2,
+    delegatecall::_evm_delegatecall,
+};
 use core::ptr::null_mut;
 use fluentbase_codec::{BufferDecoder, Encoder};
 use fluentbase_core_api::{
     api::CoreInput,
-    bindings::{EvmCallMethodInput, EvmCreate2MethodInput, EvmCreateMethodInput, EvmMethodName},
+    bindings::{
+        EvmCallMethodInput,
+        EvmCallcodeMethodInput,
+        EvmCreate2MethodInput,
+        EvmCreateMethodInput,
+        EvmDelegatecallMethodInput,
+        EvmMethodName,
+    },
 };
 use fluentbase_sdk::{evm::ExecutionContext, LowLevelAPI, LowLevelSDK};
 
@@ -73,5 +86,40 @@ pub fn main() {
                 panic!("call method failed, exit code: {}", exit_code.into_i32())
             }
         }
+        EvmMethodName::EvmDelegatecall => {
+            let method_input = decode_input!(core_input, EvmDelegatecallMethodInput);
+            let exit_code = _evm_delegatecall(
+                method_input.gas_limit,
+                method_input.callee_address20.as_ptr(),
+                method_input.args.as_ptr(),
+                method_input.args.len() as u32,
+                null_mut(),
+                0,
+            );
+            if !exit_code.is_ok() {
+                panic!(
+                    "delegatecall method failed, exit code: {}",
+                    exit_code.into_i32()
+                )
+            }
+        }
+        EvmMethodName::EvmCallcode => {
+            let method_input = decode_input!(core_input, EvmCallcodeMethodInput);
+            let exit_code = _evm_callcode(
+                method_input.gas_limit,
+                method_input.callee_address20.as_ptr(),
+                method_input.value32.as_ptr(),
+                method_input.args.as_ptr(),
+                method_input.args.len() as u32,
+                null_mut(),
+                0,
+            );
+            if !exit_code.is_ok() {
+                panic!(
+                    "callcode method failed, exit code: {}",
+                    exit_code.into_i32()
+                )
+            }
+        }
     }
 }
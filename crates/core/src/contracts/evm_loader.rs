@@ -21,17 +21,19 @@ pub fn main() {
     );
     let core_input = CoreInput::new(EVM_CALL_METHOD_ID, method_data.encode_to_vec(0));
     contract_input_data.contract_input = core_input.encode_to_vec(0).into();
-    contract_input_data.contract_address = ECL_CONTRACT_ADDRESS;
+    // ECL is executed by its bytecode hash, the contract's address is kept in the context because
+    // EVM storage and logs are bound to it
     let ecl_account = Account::new_from_jzkt(&ECL_CONTRACT_ADDRESS);
     let contract_input_data_vec = contract_input_data.encode_to_vec(0);
     let rwasm_bytecode_hash = ecl_account.rwasm_bytecode_hash;
+    let mut fuel = ExecutionContext::gas_to_fuel(gas_limit as u64);
     let exit_code = LowLevelSDK::sys_exec_hash(
         rwasm_bytecode_hash.as_ptr(),
         contract_input_data_vec.as_ptr(),
         contract_input_data_vec.len() as u32,
         core::ptr::null_mut(),
         0,
-        &gas_limit as *const u32,
+        &mut fuel as *mut u64,
         STATE_MAIN,
     );
     if exit_code != 0 {
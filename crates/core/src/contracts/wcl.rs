@@ -1,11 +1,19 @@
-use crate::wasm::{call::_wasm_call, create::_wasm_create, create2::_wasm_create2};
+use crate::wasm::{
+    call::_wasm_call,
+    callcode::_wasm_callcode,
+    create::_wasm_create,
+    create2::_wasm_create2,
+    delegatecall::_wasm_delegatecall,
+};
 use fluentbase_codec::{BufferDecoder, Encoder};
 use fluentbase_core_api::{
     api::CoreInput,
     bindings::{
         WasmCallMethodInput,
+        WasmCallcodeMethodInput,
         WasmCreate2MethodInput,
         WasmCreateMethodInput,
+        WasmDelegatecallMethodInput,
         WasmMethodName,
     },
 };
@@ -75,6 +83,37 @@ pub fn main() {
                     panic!("call method failed, exit code: {}", exit_code.into_i32())
                 }
             }
+            WasmMethodName::WasmDelegatecall => {
+                let method_input = decode_input!(core_input, WasmDelegatecallMethodInput);
+                let exit_code = _wasm_delegatecall(
+                    method_input.gas_limit,
+                    method_input.callee_address20.as_ptr(),
+                    method_input.args.as_ptr(),
+                    method_input.args.len() as u32,
+                );
+                if !exit_code.is_ok() {
+                    panic!(
+                        "delegatecall method failed, exit code: {}",
+                        exit_code.into_i32()
+                    )
+                }
+            }
+            WasmMethodName::WasmCallcode => {
+                let method_input = decode_input!(core_input, WasmCallcodeMethodInput);
+                let exit_code = _wasm_callcode(
+                    method_input.gas_limit,
+                    method_input.callee_address20.as_ptr(),
+                    method_input.value32.as_ptr(),
+                    method_input.args.as_ptr(),
+                    method_input.args.len() as u32,
+                );
+                if !exit_code.is_ok() {
+                    panic!(
+                        "callcode method failed, exit code: {}",
+                        exit_code.into_i32()
+                    )
+                }
+            }
         }
     } else {
         panic!("unknown method id: {}", core_input.method_id);
@@ -119,6 +158,6 @@ mod tests {
         LowLevelSDK::with_jzkt(journal_ref);
         LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
         super::main();
-        assert!(LowLevelSDK::get_test_output().len() > 0);
+        assert_eq!(LowLevelSDK::get_test_output().len(), 20);
     }
 }
@@ -1,6 +1,7 @@
 pub mod address;
 pub mod balance;
 pub mod call;
+pub mod callcode;
 pub mod calldatacopy;
 pub mod calldataload;
 pub mod calldatasize;
@@ -11,6 +12,8 @@ pub mod codesize;
 pub mod create;
 // #[cfg(not(any(feature = "evm_loader", feature = "wcl")))]
 pub mod create2;
+pub mod delegatecall;
+pub(crate) mod dispatch;
 pub mod extcodecopy;
 pub mod extcodehash;
 pub mod extcodesize;
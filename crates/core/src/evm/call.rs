@@ -1,4 +1,4 @@
-use crate::{account::Account, fluent_host::FluentHost, helpers::DefaultEvmSpec};
+use crate::{account::Account, evm::dispatch::run_interpreter};
 use alloc::boxed::Box;
 use core::ptr;
 use fluentbase_sdk::{
@@ -9,12 +9,10 @@ use fluentbase_sdk::{
 use fluentbase_types::{Address, ExitCode};
 use revm_interpreter::{
     analysis::to_analysed,
-    opcode::make_instruction_table,
     primitives::Bytecode,
     BytecodeLocked,
     Contract,
     Interpreter,
-    SharedMemory,
 };
 
 #[no_mangle]
@@ -47,29 +45,50 @@ pub fn _evm_call(
             Err(exit_code) => return exit_code,
         }
     };
+    let input = unsafe { &*ptr::slice_from_raw_parts(args_offset, args_size as usize) };
+    exec_evm_bytecode(
+        &callee_account,
+        callee_address,
+        caller_address,
+        value,
+        input,
+        gas_limit,
+        is_static,
+        ret_offset,
+        ret_size,
+    )
+}
+
+/// Executes EVM bytecode of the code account within the passed context (address, caller and
+/// value), it's shared by CALL, CALLCODE and DELEGATECALL that differ by the context only
+pub(crate) fn exec_evm_bytecode(
+    code_account: &Account,
+    address: Address,
+    caller: Address,
+    value: U256,
+    input: &[u8],
+    gas_limit: u32,
+    is_static: bool,
+    ret_offset: *mut u8,
+    ret_size: u32,
+) -> ExitCode {
     // load bytecode and convert it to analysed (yes, too slow)
     let bytecode = BytecodeLocked::try_from(to_analysed(Bytecode::new_raw(
-        callee_account.load_source_bytecode(),
+        code_account.load_source_bytecode(),
     )))
     .unwrap();
     // initiate contract instance and pass it to interpreter for and EVM transition
     let contract = Contract {
-        input: unsafe { &*ptr::slice_from_raw_parts(args_offset, args_size as usize) }.into(),
-        hash: callee_account.source_bytecode_hash,
+        input: input.to_vec().into(),
+        hash: code_account.source_bytecode_hash,
         bytecode,
-        address: callee_address,
-        caller: caller_address,
+        address,
+        caller,
         value,
     };
     let mut interpreter = Interpreter::new(Box::new(contract), gas_limit as u64, is_static);
-    let instruction_table = make_instruction_table::<FluentHost, DefaultEvmSpec>();
-    let mut host = FluentHost::default();
-    let shared_memory = SharedMemory::new();
-    let result = match interpreter
-        .run(shared_memory, &instruction_table, &mut host)
-        .into_result_return()
-    {
-        Some(v) => v,
+    let result = match run_interpreter(&mut interpreter) {
+        Some(result) => result,
         None => return ExitCode::EVMCallError,
     };
     let exit_code = if result.is_error() {
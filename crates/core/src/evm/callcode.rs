@@ -0,0 +1,43 @@
+use crate::{account::Account, evm::call::exec_evm_bytecode};
+use core::ptr;
+use fluentbase_sdk::evm::{ExecutionContext, U256};
+use fluentbase_types::{Address, ExitCode};
+
+#[no_mangle]
+pub fn _evm_callcode(
+    gas_limit: u32,
+    callee_address20_offset: *const u8,
+    value32_offset: *const u8,
+    args_offset: *const u8,
+    args_size: u32,
+    ret_offset: *mut u8,
+    ret_size: u32,
+) -> ExitCode {
+    let value = U256::from_be_slice(unsafe { &*ptr::slice_from_raw_parts(value32_offset, 32) });
+    // for static calls passing value is not allowed according to standards
+    let is_static = ExecutionContext::contract_is_static();
+    if is_static && value != U256::ZERO {
+        return ExitCode::WriteProtection;
+    }
+    // read callee address based on the pass parameter, only its bytecode is used
+    let callee_address =
+        Address::from_slice(unsafe { &*ptr::slice_from_raw_parts(callee_address20_offset, 20) });
+    let callee_account = Account::new_from_jzkt(&callee_address);
+    // value is sent from the current contract to itself, so the balance is only checked
+    let address = ExecutionContext::contract_address();
+    if Account::new_from_jzkt(&address).balance < value {
+        return ExitCode::InsufficientBalance;
+    }
+    let input = unsafe { &*ptr::slice_from_raw_parts(args_offset, args_size as usize) };
+    exec_evm_bytecode(
+        &callee_account,
+        address,
+        address,
+        value,
+        input,
+        gas_limit,
+        is_static,
+        ret_offset,
+        ret_size,
+    )
+}
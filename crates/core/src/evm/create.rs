@@ -1,20 +1,14 @@
-use crate::{
-    account::Account,
-    fluent_host::FluentHost,
-    helpers::{calc_create_address, DefaultEvmSpec},
-};
+use crate::{account::Account, evm::dispatch::run_interpreter, helpers::calc_create_address};
 use alloc::boxed::Box;
 use core::ptr;
 use fluentbase_sdk::evm::{ExecutionContext, U256};
 use fluentbase_types::ExitCode;
 use revm_interpreter::{
     analysis::to_analysed,
-    opcode::make_instruction_table,
     primitives::{Bytecode, Bytes},
     BytecodeLocked,
     Contract,
     Interpreter,
-    SharedMemory,
     MAX_CODE_SIZE,
 };
 
@@ -70,16 +64,9 @@ pub fn _evm_create(
         value,
     };
     let mut interpreter = Interpreter::new(Box::new(contract), gas_limit as u64, false);
-    let instruction_table = make_instruction_table::<FluentHost, DefaultEvmSpec>();
-    let mut host = FluentHost::default();
-    let shared_memory = SharedMemory::new();
-    let result = if let Some(v) = interpreter
-        .run(shared_memory, &instruction_table, &mut host)
-        .into_result_return()
-    {
-        v
-    } else {
-        return ExitCode::EVMCreateError;
+    let result = match run_interpreter(&mut interpreter) {
+        Some(result) => result,
+        None => return ExitCode::EVMCreateError,
     };
 
     if result.is_error() {
@@ -92,6 +79,8 @@ pub fn _evm_create(
         return ExitCode::ContractSizeLimit;
     }
 
+    // account might be changed by the constructor's sub calls, so it must be reloaded
+    let mut callee_account = Account::new_from_jzkt(&deployed_contract_address);
     callee_account.update_source_bytecode(&result.output);
     callee_account.update_rwasm_bytecode(
         &include_bytes!("../../../contracts/assets/evm_loader_contract.rwasm").into(),
@@ -0,0 +1,89 @@
+use crate::{account::Account, evm::call::exec_evm_bytecode};
+use core::ptr;
+use fluentbase_sdk::evm::ExecutionContext;
+use fluentbase_types::{Address, ExitCode};
+
+#[no_mangle]
+pub fn _evm_delegatecall(
+    gas_limit: u32,
+    callee_address20_offset: *const u8,
+    args_offset: *const u8,
+    args_size: u32,
+    ret_offset: *mut u8,
+    ret_size: u32,
+) -> ExitCode {
+    // read callee address based on the pass parameter, only its bytecode is used
+    let callee_address =
+        Address::from_slice(unsafe { &*ptr::slice_from_raw_parts(callee_address20_offset, 20) });
+    let callee_account = Account::new_from_jzkt(&callee_address);
+    // address, caller and value are inherited from the current context, so there is no transfer
+    let input = unsafe { &*ptr::slice_from_raw_parts(args_offset, args_size as usize) };
+    exec_evm_bytecode(
+        &callee_account,
+        ExecutionContext::contract_address(),
+        ExecutionContext::contract_caller(),
+        ExecutionContext::contract_value(),
+        input,
+        gas_limit,
+        ExecutionContext::contract_is_static(),
+        ret_offset,
+        ret_size,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fluentbase_codec::Encoder;
+    use fluentbase_sdk::{
+        evm::{ContractInput, U256},
+        LowLevelAPI,
+        LowLevelSDK,
+    };
+    use fluentbase_types::{address, Bytes};
+
+    fn storage_at(address: &Address, slot: u64) -> U256 {
+        let mut value32 = [0u8; 32];
+        LowLevelSDK::jzkt_load(
+            address.into_word().as_ptr(),
+            U256::from(slot).as_le_slice().as_ptr(),
+            value32.as_mut_ptr(),
+        );
+        U256::from_le_bytes(value32)
+    }
+
+    #[test]
+    fn test_delegatecall_keeps_context() {
+        const PROXY: Address = address!("0000000000000000000000000000000000000001");
+        const CALLER: Address = address!("0000000000000000000000000000000000000002");
+        const IMPLEMENTATION: Address = address!("0000000000000000000000000000000000000003");
+        let contract_input = ContractInput {
+            contract_address: PROXY,
+            contract_caller: CALLER,
+            ..Default::default()
+        };
+        LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+        LowLevelSDK::with_default_jzkt();
+        // CALLER PUSH1 0 SSTORE ADDRESS PUSH1 1 SSTORE STOP
+        let mut implementation = Account::new_from_jzkt(&IMPLEMENTATION);
+        implementation.update_source_bytecode(&Bytes::from_static(&[
+            0x33, 0x60, 0x00, 0x55, 0x30, 0x60, 0x01, 0x55, 0x00,
+        ]));
+        let exit_code = _evm_delegatecall(
+            100_000,
+            IMPLEMENTATION.as_ptr(),
+            ptr::null(),
+            0,
+            ptr::null_mut(),
+            0,
+        );
+        assert_eq!(exit_code, ExitCode::Ok);
+        // storage of the proxy is modified, the implementation's storage stays untouched
+        assert_eq!(
+            storage_at(&PROXY, 0),
+            U256::from_be_slice(CALLER.as_slice())
+        );
+        assert_eq!(storage_at(&PROXY, 1), U256::from_be_slice(PROXY.as_slice()));
+        assert_eq!(storage_at(&IMPLEMENTATION, 0), U256::ZERO);
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{fluent_host::FluentHost, helpers::evm_instruction_table};
+use fluentbase_sdk::call::{self, CallFrame, FrameResult};
+use fluentbase_types::{Address, ExitCode};
+use revm_interpreter::{
+    primitives::{Bytes, CreateScheme},
+    CallInputs,
+    CallOutcome,
+    CallScheme,
+    CreateInputs,
+    CreateOutcome,
+    Gas,
+    InstructionResult,
+    Interpreter,
+    InterpreterAction,
+    InterpreterResult,
+    SharedMemory,
+};
+
+/// Runs the interpreter until it returns, sub calls and sub creates requested by the interpreter
+/// are executed as child frames and their outcomes are inserted back.
+///
+/// Gas limits of child frames are already reduced by the interpreter according to EIP-150 (all
+/// but one 64th of the remaining gas), the unspent gas is returned by the interpreter as well.
+pub(crate) fn run_interpreter(interpreter: &mut Interpreter) -> Option<InterpreterResult> {
+    let instruction_table = evm_instruction_table();
+    let mut host = FluentHost::default();
+    let mut shared_memory = SharedMemory::new();
+    loop {
+        match interpreter.run(shared_memory, &instruction_table, &mut host) {
+            InterpreterAction::Return { result } => return Some(result),
+            InterpreterAction::Call { inputs } => {
+                shared_memory = interpreter.take_memory();
+                let outcome = dispatch_call(&inputs);
+                interpreter.insert_call_outcome(&mut shared_memory, outcome);
+            }
+            InterpreterAction::Create { inputs } => {
+                shared_memory = interpreter.take_memory();
+                interpreter.insert_create_outcome(dispatch_create(&inputs));
+            }
+            InterpreterAction::None => return None,
+        }
+    }
+}
+
+/// Executes a sub call requested by the EVM interpreter (CALL, CALLCODE, DELEGATECALL or
+/// STATICCALL) and returns its outcome back to the interpreter.
+///
+/// The call is executed by the SDK's dispatcher, so EVM and WASM callees are handled in the same
+/// way as calls made by WASM contracts.
+pub(crate) fn dispatch_call(inputs: &CallInputs) -> CallOutcome {
+    let scheme = match inputs.context.scheme {
+        CallScheme::Call => call::CallScheme::Call,
+        CallScheme::StaticCall => call::CallScheme::StaticCall,
+        CallScheme::DelegateCall => call::CallScheme::DelegateCall,
+        CallScheme::CallCode => call::CallScheme::CallCode,
+    };
+    let frame_result = call::dispatch_call(&CallFrame {
+        scheme,
+        code_address: inputs.context.code_address,
+        address: inputs.context.address,
+        caller: inputs.context.caller,
+        value: inputs.context.apparent_value,
+        input: inputs.input.as_ref(),
+        gas_limit: inputs.gas_limit,
+        is_static: inputs.is_static,
+    });
+    let (result, gas) = frame_outcome(&frame_result, inputs.gas_limit);
+    CallOutcome::new(
+        InterpreterResult {
+            result,
+            output: frame_result.output,
+            gas,
+        },
+        inputs.return_memory_offset.clone(),
+    )
+}
+
+/// Executes a sub create requested by the EVM interpreter (CREATE or CREATE2) and returns its
+/// outcome back to the interpreter.
+///
+/// Init code is deployed by the SDK's dispatcher in the same way as it's done for WASM
+/// contracts, the interpreter's contract becomes a deployer.
+pub(crate) fn dispatch_create(inputs: &CreateInputs) -> CreateOutcome {
+    let salt = match inputs.scheme {
+        CreateScheme::Create => None,
+        CreateScheme::Create2 { salt } => Some(salt),
+    };
+    let frame_result = call::dispatch_create(
+        inputs.caller,
+        inputs.value,
+        inputs.init_code.as_ref(),
+        salt,
+        inputs.gas_limit,
+    );
+    let (result, gas) = frame_outcome(&frame_result, inputs.gas_limit);
+    let (output, address) = if result == InstructionResult::Return {
+        (
+            Bytes::new(),
+            Some(Address::from_slice(&frame_result.output)),
+        )
+    } else {
+        // output is kept as a revert reason
+        (frame_result.output, None)
+    };
+    CreateOutcome::new(
+        InterpreterResult {
+            result,
+            output,
+            gas,
+        },
+        address,
+    )
+}
+
+/// Maps the frame's exit code to the interpreter's result and charges the frame's gas
+fn frame_outcome(frame_result: &FrameResult, gas_limit: u64) -> (InstructionResult, Gas) {
+    let mut gas = Gas::new(gas_limit);
+    gas.record_cost(frame_result.gas_used);
+    let exit_code = frame_result.exit_code;
+    let result = if exit_code == ExitCode::Ok.into_i32() {
+        InstructionResult::Return
+    } else if exit_code == ExitCode::CallDepthOverflow.into_i32() {
+        InstructionResult::CallTooDeep
+    } else if exit_code == ExitCode::InsufficientBalance.into_i32() {
+        InstructionResult::OutOfFunds
+    } else if exit_code == ExitCode::WriteProtection.into_i32() {
+        InstructionResult::StateChangeDuringStaticCall
+    } else if exit_code == ExitCode::OutOfFuel.into_i32() {
+        InstructionResult::OutOfGas
+    } else {
+        InstructionResult::Revert
+    };
+    (result, gas)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fluentbase_codec::Encoder;
+    use fluentbase_sdk::{evm::ContractInput, LowLevelSDK};
+    use fluentbase_types::{CALL_STACK_LIMIT, U256};
+
+    #[test]
+    fn test_dispatch_create_checks_call_depth() {
+        let contract_input = ContractInput {
+            contract_depth: CALL_STACK_LIMIT,
+            ..Default::default()
+        };
+        LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+        LowLevelSDK::with_default_jzkt();
+        let outcome = dispatch_create(&CreateInputs {
+            caller: Address::ZERO,
+            scheme: CreateScheme::Create,
+            value: U256::ZERO,
+            // PUSH1 0 PUSH1 0 RETURN
+            init_code: Bytes::from_static(&[0x60, 0x00, 0x60, 0x00, 0xf3]),
+            gas_limit: 100_000,
+        });
+        assert_eq!(
+            *outcome.instruction_result(),
+            InstructionResult::CallTooDeep
+        );
+        assert_eq!(outcome.address, None);
+        // gas isn't spent if the frame isn't created
+        assert_eq!(outcome.result.gas.remaining(), 100_000);
+    }
+}
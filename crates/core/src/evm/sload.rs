@@ -1,11 +1,10 @@
-use crate::helpers::calc_storage_key;
-use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
+use fluentbase_sdk::{evm::ExecutionContext, LowLevelAPI, LowLevelSDK};
 use fluentbase_types::ExitCode;
 
 #[no_mangle]
 pub fn _evm_sload(slot32_offset: *const u8, value32_offset: *mut u8) -> Result<bool, ExitCode> {
-    let storage_key = calc_storage_key(slot32_offset);
-    let is_cold = LowLevelSDK::jzkt_get(storage_key.as_ptr(), 0, value32_offset);
+    let address32 = ExecutionContext::contract_address().into_word();
+    let is_cold = LowLevelSDK::jzkt_load(address32.as_ptr(), slot32_offset, value32_offset);
     Ok(is_cold)
 }
 
@@ -1,16 +1,10 @@
-use crate::helpers::calc_storage_key;
-use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
+use fluentbase_sdk::{evm::ExecutionContext, LowLevelAPI, LowLevelSDK};
 use fluentbase_types::ExitCode;
 
 #[no_mangle]
 pub fn _evm_sstore(slot32_offset: *const u8, value32_offset: *const u8) -> Result<bool, ExitCode> {
-    let storage_key = calc_storage_key(slot32_offset);
-    LowLevelSDK::jzkt_update(
-        storage_key.as_ptr(),
-        0,
-        value32_offset as *const [u8; 32],
-        32,
-    );
+    let address32 = ExecutionContext::contract_address().into_word();
+    LowLevelSDK::jzkt_store(address32.as_ptr(), slot32_offset, value32_offset);
     Ok(true)
 }
 
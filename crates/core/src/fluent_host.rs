@@ -1,14 +1,11 @@
-use crate::{
-    account::Account,
-    account_types::MAX_BYTECODE_SIZE,
-    evm::{sload::_evm_sload, sstore::_evm_sstore},
-};
-use alloc::{vec, vec::Vec};
+use crate::{account::Account, account_types::MAX_BYTECODE_SIZE};
+use alloc::vec::Vec;
 use fluentbase_sdk::{evm::ExecutionContext, Bytes32, LowLevelAPI, LowLevelSDK};
 use revm_interpreter::{
     primitives::{
         Address,
         AnalysisKind,
+        BlobExcessGasAndPrice,
         BlockEnv,
         Bytecode,
         Bytes,
@@ -69,21 +66,27 @@ impl FluentHost {
                         .to_be_bytes()
                         .as_slice(),
                 ),
-                prevrandao: None,
-                blob_excess_gas_and_price: None,
+                prevrandao: Some(ExecutionContext::block_prevrandao()),
+                blob_excess_gas_and_price: Some(BlobExcessGasAndPrice {
+                    excess_blob_gas: 0,
+                    blob_gasprice: ExecutionContext::tx_blob_gas_price().saturating_to(),
+                }),
             },
             tx: TxEnv {
                 caller: Address::from_slice(ExecutionContext::tx_caller().as_ref()),
-                gas_limit: Default::default(),
+                gas_limit: ExecutionContext::tx_gas_limit(),
                 gas_price: ExecutionContext::tx_gas_price(),
                 transact_to: TransactTo::Call(Address::ZERO), // will do nothing
                 value: ExecutionContext::contract_value(),
                 data: Default::default(), // no data?
-                nonce: None,              // no checks
-                chain_id: None,           // no checks
-                access_list: vec![],
-                gas_priority_fee: None,
-                blob_hashes: vec![],
+                nonce: Some(ExecutionContext::tx_nonce()),
+                chain_id: None, // no checks
+                access_list: ExecutionContext::tx_access_list()
+                    .into_iter()
+                    .map(|item| (item.address, item.storage_keys))
+                    .collect(),
+                gas_priority_fee: ExecutionContext::tx_gas_priority_fee(),
+                blob_hashes: ExecutionContext::tx_blob_hashes(),
                 max_fee_per_blob_gas: None,
             },
         }
@@ -150,21 +153,34 @@ impl Host for FluentHost {
     }
 
     #[inline]
-    fn sload(&mut self, _address: Address, index: U256) -> Option<(U256, bool)> {
+    fn sload(&mut self, address: Address, index: U256) -> Option<(U256, bool)> {
+        // storage is bound to the interpreter's address that differs from the context's address
+        // for nested frames (f.e. constructors or delegate calls)
+        let address_word = address.into_word();
         let mut slot_value32 = Bytes32::default();
-        let is_cold = _evm_sload(index.as_le_slice().as_ptr(), slot_value32.as_mut_ptr()).ok()?;
+        let is_cold = LowLevelSDK::jzkt_load(
+            address_word.as_ptr(),
+            index.as_le_slice().as_ptr(),
+            slot_value32.as_mut_ptr(),
+        );
         Some((U256::from_be_bytes(slot_value32), is_cold))
     }
 
     #[inline]
-    fn sstore(&mut self, _address: Address, index: U256, value: U256) -> Option<SStoreResult> {
+    fn sstore(&mut self, address: Address, index: U256, value: U256) -> Option<SStoreResult> {
+        let address_word = address.into_word();
         let mut previous = U256::default();
-        _evm_sload(index.as_le_slice().as_ptr(), unsafe {
-            previous.as_le_slice_mut().as_mut_ptr()
-        })
-        .ok()?;
-        let is_cold =
-            _evm_sstore(index.as_le_slice().as_ptr(), value.as_le_slice().as_ptr()).ok()?;
+        LowLevelSDK::jzkt_load(
+            address_word.as_ptr(),
+            index.as_le_slice().as_ptr(),
+            unsafe { previous.as_le_slice_mut().as_mut_ptr() },
+        );
+        LowLevelSDK::jzkt_store(
+            address_word.as_ptr(),
+            index.as_le_slice().as_ptr(),
+            value.as_le_slice().as_ptr(),
+        );
+        let is_cold = true;
         return Some(SStoreResult {
             original_value: previous,
             present_value: previous,
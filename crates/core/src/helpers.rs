@@ -1,4 +1,4 @@
-use crate::account_types::JZKT_ACCOUNT_BALANCE_FIELD;
+use crate::{account_types::JZKT_ACCOUNT_BALANCE_FIELD, fluent_host::FluentHost};
 use alloc::{boxed::Box, string::ToString, vec, vec::Vec};
 use byteorder::{ByteOrder, LittleEndian};
 use fluentbase_sdk::{
@@ -17,13 +17,34 @@ use fluentbase_types::{
     STATE_MAIN,
     U256,
 };
+use revm_interpreter::{
+    opcode::{make_instruction_table, InstructionTable},
+    primitives::{spec_to_generic, Spec, SpecId},
+};
 use rwasm::{
     engine::{bytecode::Instruction, RwasmConfig, StateRouterConfig},
     rwasm::{BinaryFormat, BinaryFormatWriter, RwasmModule},
     Error,
 };
 
-pub type DefaultEvmSpec = revm_interpreter::primitives::ShanghaiSpec;
+/// Spec that is used if the execution context has an unknown spec id
+pub type DefaultEvmSpec = revm_interpreter::primitives::CancunSpec;
+
+/// Returns the spec id that is passed by revm through the execution context, the default spec is
+/// used if it isn't set
+#[inline(always)]
+pub fn evm_spec_id() -> SpecId {
+    ExecutionContext::env_spec_id()
+        .and_then(SpecId::try_from_u8)
+        .unwrap_or(DefaultEvmSpec::SPEC_ID)
+}
+
+/// Makes instruction table for the spec from the execution context, so opcode availability and
+/// gas costs match the spec that revm uses to process the transaction
+#[inline(always)]
+pub(crate) fn evm_instruction_table() -> InstructionTable<FluentHost> {
+    spec_to_generic!(evm_spec_id(), make_instruction_table::<FluentHost, SPEC>())
+}
 
 #[inline]
 pub(crate) fn get_contract_input_offset_and_len() -> (u32, u32) {
@@ -85,6 +106,8 @@ pub fn calc_create2_address(deployer: &Address, salt: &B256, init_code_hash: &B2
 #[inline(always)]
 pub fn rwasm_module(wasm_binary: &[u8]) -> Result<RwasmModule, Error> {
     let mut config = RwasmModule::default_config(None);
+    // the runtime can't execute floats, so reject them during compilation
+    config.floats(false);
     config.rwasm_config(RwasmConfig {
         state_router: Some(StateRouterConfig {
             states: Box::new([
@@ -118,6 +141,7 @@ pub fn wasm2rwasm(wasm_binary: &[u8]) -> Result<Vec<u8>, ExitCode> {
 
 #[inline(always)]
 pub fn rwasm_exec(bytecode: &[u8], input: &[u8], gas_limit: u32, is_deploy: bool) {
+    let mut fuel = ExecutionContext::gas_to_fuel(gas_limit as u64);
     let exit_code = LowLevelSDK::sys_exec(
         bytecode.as_ptr(),
         bytecode.len() as u32,
@@ -125,7 +149,7 @@ pub fn rwasm_exec(bytecode: &[u8], input: &[u8], gas_limit: u32, is_deploy: bool
         input.len() as u32,
         core::ptr::null_mut(),
         0,
-        &gas_limit as *const u32,
+        &mut fuel as *mut u64,
         if is_deploy { STATE_DEPLOY } else { STATE_MAIN },
     );
     if exit_code != 0 {
@@ -135,48 +159,17 @@ pub fn rwasm_exec(bytecode: &[u8], input: &[u8], gas_limit: u32, is_deploy: bool
 
 #[inline(always)]
 pub fn rwasm_exec_hash(code_hash32: &[u8], input: &[u8], gas_limit: u32, is_deploy: bool) {
+    let mut fuel = ExecutionContext::gas_to_fuel(gas_limit as u64);
     let exit_code = LowLevelSDK::sys_exec_hash(
         code_hash32.as_ptr(),
         input.as_ptr(),
         input.len() as u32,
         core::ptr::null_mut(),
         0,
-        &gas_limit as *const u32,
+        &mut fuel as *mut u64,
         if is_deploy { STATE_DEPLOY } else { STATE_MAIN },
     );
     if exit_code != 0 {
         panic!("failed to execute rwasm bytecode, exit code: {}", exit_code);
     }
 }
-
-const DOMAIN: [u8; 32] = [0u8; 32];
-
-#[inline(always)]
-pub(crate) fn calc_storage_key(slot32_offset: *const u8) -> [u8; 32] {
-    let mut slot0: [u8; 32] = [0u8; 32];
-    let mut slot1: [u8; 32] = [0u8; 32];
-    // split slot32 into two 16 byte values (slot is always 32 bytes)
-    unsafe {
-        core::ptr::copy(slot32_offset.offset(0), slot0.as_mut_ptr(), 16);
-        core::ptr::copy(slot32_offset.offset(16), slot1.as_mut_ptr(), 16);
-    }
-    // pad address to 32 bytes value
-    let mut address32: [u8; 32] = [0u8; 32];
-    let address = ExecutionContext::contract_address();
-    address32[12..].copy_from_slice(address.as_slice());
-    // compute a storage key, where formula is `p(address, p(slot_0, slot_1))`
-    let mut storage_key: [u8; 32] = [0u8; 32];
-    LowLevelSDK::crypto_poseidon2(
-        slot0.as_ptr(),
-        slot1.as_ptr(),
-        DOMAIN.as_ptr(),
-        storage_key.as_mut_ptr(),
-    );
-    LowLevelSDK::crypto_poseidon2(
-        address32.as_ptr(),
-        storage_key.as_ptr(),
-        DOMAIN.as_ptr(),
-        storage_key.as_mut_ptr(),
-    );
-    storage_key
-}
@@ -1,3 +1,7 @@
 pub mod call;
+pub mod callcode;
 pub mod create;
 pub mod create2;
+pub mod delegatecall;
+pub mod gas;
+pub mod validation;
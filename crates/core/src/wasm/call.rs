@@ -5,7 +5,7 @@ use fluentbase_sdk::{
     LowLevelAPI,
     LowLevelSDK,
 };
-use fluentbase_types::{Address, Bytes, ExitCode, STATE_MAIN};
+use fluentbase_types::{Address, Bytes, ExitCode, CALL_STACK_LIMIT, STATE_MAIN, STATE_STATIC_FLAG};
 
 #[no_mangle]
 pub fn _wasm_call(
@@ -41,29 +41,48 @@ pub fn _wasm_call(
         }
     };
 
-    let contract_input = ContractInput {
-        journal_checkpoint: ExecutionContext::journal_checkpoint().into(),
-        contract_gas_limit: gas_limit as u64,
-        contract_address: callee_address,
-        contract_caller: ExecutionContext::contract_caller(),
-        contract_input: Bytes::from_static(args),
-        tx_caller: ExecutionContext::tx_caller(),
-        ..Default::default()
-    };
-    let contract_input_vec = contract_input.encode_to_vec(0);
-
     if value != U256::ZERO {
         return ExitCode::UnknownError;
     };
-    let bytecode_hash = callee_account.rwasm_bytecode_hash;
+    // env, block and tx info are inherited from the current context
+    let mut contract_input = ExecutionContext::contract_input_full();
+    contract_input.contract_gas_limit = gas_limit as u64;
+    contract_input.contract_address = callee_address;
+    contract_input.contract_caller = caller_address;
+    contract_input.contract_input = Bytes::from_static(args);
+    contract_input.contract_value = value;
+    exec_wasm_bytecode(&callee_account, contract_input, gas_limit)
+}
+
+/// Executes rWASM bytecode of the code account with the passed contract input, it's shared by
+/// CALL, CALLCODE and DELEGATECALL that differ by the context only
+pub(crate) fn exec_wasm_bytecode(
+    code_account: &Account,
+    mut contract_input: ContractInput,
+    gas_limit: u32,
+) -> ExitCode {
+    // check call stack limit
+    let depth = ExecutionContext::contract_depth();
+    if depth >= CALL_STACK_LIMIT {
+        return ExitCode::CallDepthOverflow;
+    }
+    contract_input.contract_depth = depth + 1;
+    let state = if contract_input.contract_is_static {
+        STATE_MAIN | STATE_STATIC_FLAG
+    } else {
+        STATE_MAIN
+    };
+    let contract_input_vec = contract_input.encode_to_vec(0);
+    let bytecode_hash = code_account.rwasm_bytecode_hash;
+    let mut fuel = ExecutionContext::gas_to_fuel(gas_limit as u64);
     let exit_code = LowLevelSDK::sys_exec_hash(
         bytecode_hash.as_ptr(),
         contract_input_vec.as_ptr(),
         contract_input_vec.len() as u32,
         core::ptr::null_mut(),
         0,
-        &gas_limit as *const u32,
-        STATE_MAIN,
+        &mut fuel as *mut u64,
+        state,
     );
     if exit_code != ExitCode::Ok.into_i32() {
         panic!("wasm call failed, exit code: {}", exit_code);
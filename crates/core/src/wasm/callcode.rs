@@ -0,0 +1,40 @@
+use crate::{account::Account, wasm::call::exec_wasm_bytecode};
+use fluentbase_sdk::evm::{ExecutionContext, U256};
+use fluentbase_types::{Address, Bytes, ExitCode};
+
+#[no_mangle]
+pub fn _wasm_callcode(
+    gas_limit: u32,
+    callee_address20_offset: *const u8,
+    value32_offset: *const u8,
+    args_offset: *const u8,
+    args_size: u32,
+) -> ExitCode {
+    // parse input value
+    let value =
+        U256::from_be_slice(unsafe { &*core::ptr::slice_from_raw_parts(value32_offset, 32) });
+    // don't allow to do static calls with non zero value
+    let is_static = ExecutionContext::contract_is_static();
+    if is_static && value != U256::ZERO {
+        return ExitCode::WriteProtection;
+    }
+    // parse args
+    let args = unsafe { &*core::ptr::slice_from_raw_parts(args_offset, args_size as usize) };
+    // parse callee address, only its bytecode is used
+    let callee_address = Address::from_slice(unsafe {
+        &*core::ptr::slice_from_raw_parts(callee_address20_offset, 20)
+    });
+    let callee_account = Account::new_from_jzkt(&callee_address);
+    // value is sent from the current contract to itself, so the balance is only checked
+    let address = ExecutionContext::contract_address();
+    if Account::new_from_jzkt(&address).balance < value {
+        return ExitCode::InsufficientBalance;
+    }
+    // env, block and tx info are inherited from the current context
+    let mut contract_input = ExecutionContext::contract_input_full();
+    contract_input.contract_gas_limit = gas_limit as u64;
+    contract_input.contract_caller = address;
+    contract_input.contract_input = Bytes::from_static(args);
+    contract_input.contract_value = value;
+    exec_wasm_bytecode(&callee_account, contract_input, gas_limit)
+}
@@ -1,9 +1,32 @@
 use crate::{
     account::Account,
-    helpers::{calc_create_address, read_address_from_input, rwasm_exec_hash, wasm2rwasm},
+    helpers::{calc_create_address, read_address_from_input, wasm2rwasm},
+    wasm::{
+        gas::{
+            charge_gas,
+            code_deposit_cost,
+            deploy_cost,
+            translation_cost,
+            WASM_MAX_INITCODE_SIZE,
+        },
+        validation::{validate_wasm, WasmPolicy, WASM_VERSION},
+    },
 };
-use fluentbase_sdk::evm::{ContractInput, ExecutionContext, IContractInput, U256};
-use fluentbase_types::ExitCode;
+use alloc::vec;
+use fluentbase_codec::Encoder;
+use fluentbase_sdk::{
+    evm::{
+        ContractInput,
+        ExecutionContext,
+        IContractInput,
+        IWasmDeployInput,
+        WasmDeployInput,
+        U256,
+    },
+    LowLevelAPI,
+    LowLevelSDK,
+};
+use fluentbase_types::{Address, Bytes, ExitCode, CALL_STACK_LIMIT, STATE_DEPLOY, WASM_MAGIC};
 use revm_primitives::RWASM_MAX_CODE_SIZE;
 
 #[no_mangle]
@@ -14,16 +37,36 @@ pub fn _wasm_create(
     gas_limit: u32,
     address20_offset: *mut u8,
 ) -> ExitCode {
-    // TODO: "gas calculations"
-    // TODO: "call depth stack check >= 1024"
+    // check call stack limit
+    if ExecutionContext::contract_depth() >= CALL_STACK_LIMIT {
+        return ExitCode::CallDepthOverflow;
+    }
     // check write protection
     if ExecutionContext::contract_is_static() {
         return ExitCode::WriteProtection;
     }
-    // code length can't exceed max limit
-    if code_length > RWASM_MAX_CODE_SIZE as u32 {
+    // EIP-3860: deploy code length can't exceed max limit
+    if code_length as usize > WASM_MAX_INITCODE_SIZE {
         return ExitCode::ContractSizeLimit;
     }
+    let code = unsafe { &*core::ptr::slice_from_raw_parts(code_offset, code_length as usize) };
+    let (bytecode_wasm, args) = match decode_deploy_input(code) {
+        Ok(result) => result,
+        Err(exit_code) => return exit_code,
+    };
+    // deploy code and WASM input are charged before the translation, so binaries that can't pay
+    // for it are never translated
+    let mut charged_gas = 0u64;
+    if let Err(exit_code) = charge_gas(
+        &mut charged_gas,
+        deploy_cost(code.len(), bytecode_wasm.len()),
+        gas_limit,
+    ) {
+        return exit_code;
+    }
+    if let Err(err) = validate_wasm(bytecode_wasm, &WasmPolicy::default()) {
+        return err.into();
+    }
 
     // read value input and contract address
     let value32_slice = unsafe { &*core::ptr::slice_from_raw_parts(value32_offset, 32) };
@@ -47,9 +90,17 @@ pub fn _wasm_create(
     }
 
     // translate WASM to rWASM
-    let bytecode_wasm =
-        unsafe { &*core::ptr::slice_from_raw_parts(code_offset, code_length as usize) };
-    let bytecode_rwasm = wasm2rwasm(bytecode_wasm).unwrap();
+    let bytecode_rwasm = match wasm2rwasm(bytecode_wasm) {
+        Ok(bytecode_rwasm) => bytecode_rwasm,
+        Err(exit_code) => return exit_code,
+    };
+    if let Err(exit_code) = charge_gas(
+        &mut charged_gas,
+        translation_cost(0, bytecode_rwasm.len()),
+        gas_limit,
+    ) {
+        return exit_code;
+    }
 
     // write deployer to the trie
     deployer_account.write_to_jzkt();
@@ -57,15 +108,165 @@ pub fn _wasm_create(
     // write contract to the trie
     contract_account.update_source_bytecode(&bytecode_wasm.into());
     contract_account.update_rwasm_bytecode(&bytecode_rwasm.into());
-    rwasm_exec_hash(
-        &contract_account.rwasm_bytecode_hash.as_slice(),
-        &[],
-        gas_limit,
-        true,
-    );
+    if let Err(exit_code) = exec_deploy_bytecode(
+        &contract_account,
+        caller_address,
+        value,
+        args,
+        gas_limit - charged_gas as u32,
+    ) {
+        return exit_code;
+    }
 
     // copy output address
-    unsafe { core::ptr::copy(deployed_contract_address.as_ptr(), address20_offset, 20) }
+    unsafe {
+        core::ptr::copy(deployed_contract_address.as_ptr(), address20_offset, 20);
+    }
 
     ExitCode::Ok
 }
+
+/// Splits deploy code into the WASM binary and constructor arguments, the code is either a raw
+/// WASM binary or an encoded [`WasmDeployInput`] prefixed with the WASM magic (see
+/// [`WasmDeployInput::encode_deploy_code`]), it's distinguished from the binary by the version
+pub(crate) fn decode_deploy_input(code: &[u8]) -> Result<(&[u8], &[u8]), ExitCode> {
+    let Some(envelope) = code.strip_prefix(&WASM_MAGIC) else {
+        return Err(ExitCode::CompilationError);
+    };
+    if envelope.starts_with(&WASM_VERSION) {
+        return Ok((code, &[]));
+    }
+    if envelope.len() < WasmDeployInput::HEADER_SIZE {
+        return Err(ExitCode::CompilationError);
+    }
+    let mut value = Bytes::new();
+    let (wasm_offset, wasm_length) =
+        <WasmDeployInput as IWasmDeployInput>::Wasm::decode_field_header(envelope, &mut value);
+    let (args_offset, args_length) =
+        <WasmDeployInput as IWasmDeployInput>::Args::decode_field_header(envelope, &mut value);
+    match (
+        envelope.get(wasm_offset..wasm_offset.saturating_add(wasm_length)),
+        envelope.get(args_offset..args_offset.saturating_add(args_length)),
+    ) {
+        (Some(wasm), Some(args)) => Ok((wasm, args)),
+        _ => Err(ExitCode::CompilationError),
+    }
+}
+
+/// Runs the deploy function with constructor arguments passed as the contract input and charges
+/// gas for code deposit. Fuel consumed by the deploy function is charged by the runtime.
+///
+/// If the deploy function returns a WASM module then it replaces the deployed code (init code vs
+/// runtime code), any other returned data is stored as immutable data of the contract.
+pub(crate) fn exec_deploy_bytecode(
+    contract_account: &Account,
+    caller_address: Address,
+    value: U256,
+    args: &[u8],
+    gas_limit: u32,
+) -> Result<(), ExitCode> {
+    // env, block and tx info are inherited from the current context
+    let mut contract_input = ExecutionContext::contract_input_full();
+    contract_input.contract_gas_limit = gas_limit as u64;
+    contract_input.contract_address = contract_account.address;
+    contract_input.contract_caller = caller_address;
+    contract_input.contract_input = Bytes::copy_from_slice(args);
+    contract_input.contract_value = value;
+    contract_input.contract_depth = ExecutionContext::contract_depth() + 1;
+    let contract_input = contract_input.encode_to_vec(0);
+    let fuel_limit = ExecutionContext::gas_to_fuel(gas_limit as u64);
+    let mut fuel = fuel_limit;
+    let exit_code = LowLevelSDK::sys_exec_hash(
+        contract_account.rwasm_bytecode_hash.as_ptr(),
+        contract_input.as_ptr(),
+        contract_input.len() as u32,
+        core::ptr::null_mut(),
+        0,
+        &mut fuel as *mut u64,
+        STATE_DEPLOY,
+    );
+    if exit_code != ExitCode::Ok.into_i32() {
+        // deploy function might fail with any code, so only running out of fuel is distinguished
+        return Err(if exit_code == ExitCode::OutOfFuel.into_i32() {
+            ExitCode::OutOfFuel
+        } else {
+            ExitCode::CreateError
+        });
+    }
+    let gas_limit =
+        (gas_limit as u64).saturating_sub(ExecutionContext::fuel_to_gas(fuel_limit - fuel)) as u32;
+    let mut charged_gas = 0u64;
+
+    let output_size = LowLevelSDK::sys_output_size();
+    let mut output = vec![0u8; output_size as usize];
+    LowLevelSDK::sys_read_output(output.as_mut_ptr(), 0, output_size);
+    // deploy function might change the account (e.g. its balance), so reload it
+    let mut contract_account = Account::new_from_jzkt(&contract_account.address);
+    if !output.starts_with(&WASM_MAGIC) {
+        // init code is kept, so it must fit into the code size limit
+        let code_size = contract_account.source_bytecode_size as usize;
+        if code_size > RWASM_MAX_CODE_SIZE {
+            return Err(ExitCode::ContractSizeLimit);
+        }
+        // both WASM and rWASM bytecodes are stored
+        charge_gas(
+            &mut charged_gas,
+            code_deposit_cost(
+                code_size + contract_account.rwasm_bytecode_size as usize + output.len(),
+            ),
+            gas_limit,
+        )?;
+        if !output.is_empty() {
+            contract_account.update_immutable_data(&output.into());
+        }
+        return Ok(());
+    }
+    if output.len() > RWASM_MAX_CODE_SIZE {
+        return Err(ExitCode::ContractSizeLimit);
+    }
+    validate_wasm(&output, &WasmPolicy::runtime())?;
+    // the same as for the deploy code, WASM input is charged before the translation
+    charge_gas(
+        &mut charged_gas,
+        translation_cost(output.len(), 0) + code_deposit_cost(output.len()),
+        gas_limit,
+    )?;
+    let bytecode_rwasm = wasm2rwasm(&output)?;
+    charge_gas(
+        &mut charged_gas,
+        translation_cost(0, bytecode_rwasm.len()) + code_deposit_cost(bytecode_rwasm.len()),
+        gas_limit,
+    )?;
+    contract_account.update_source_bytecode(&output.into());
+    contract_account.update_rwasm_bytecode(&bytecode_rwasm.into());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_deploy_input() {
+        let wasm = [WASM_MAGIC, WASM_VERSION].concat();
+        assert_eq!(decode_deploy_input(&wasm), Ok((wasm.as_slice(), &[][..])));
+        let deploy_input = WasmDeployInput {
+            wasm: Bytes::from(wasm.clone()),
+            args: Bytes::from_static(&[1, 2, 3]),
+        }
+        .encode_deploy_code();
+        assert_eq!(
+            decode_deploy_input(&deploy_input),
+            Ok((wasm.as_slice(), &[1, 2, 3][..]))
+        );
+        assert_eq!(
+            decode_deploy_input(&deploy_input[..deploy_input.len() - 1]),
+            Err(ExitCode::CompilationError)
+        );
+        assert_eq!(
+            decode_deploy_input(&deploy_input[WASM_MAGIC.len()..]),
+            Err(ExitCode::CompilationError)
+        );
+        assert_eq!(decode_deploy_input(&[]), Err(ExitCode::CompilationError));
+    }
+}
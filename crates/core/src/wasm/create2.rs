@@ -1,6 +1,11 @@
 use crate::{
     account::Account,
-    helpers::{calc_create2_address, read_address_from_input, rwasm_exec_hash, wasm2rwasm},
+    helpers::{calc_create2_address, read_address_from_input, wasm2rwasm},
+    wasm::{
+        create::{decode_deploy_input, exec_deploy_bytecode},
+        gas::{charge_gas, deploy_cost, translation_cost, WASM_MAX_INITCODE_SIZE},
+        validation::{validate_wasm, WasmPolicy},
+    },
 };
 use core::ptr;
 use fluentbase_sdk::{
@@ -8,7 +13,7 @@ use fluentbase_sdk::{
     LowLevelAPI,
     LowLevelSDK,
 };
-use fluentbase_types::{ExitCode, B256};
+use fluentbase_types::{ExitCode, B256, CALL_STACK_LIMIT};
 
 #[no_mangle]
 pub fn _wasm_create2(
@@ -19,12 +24,37 @@ pub fn _wasm_create2(
     gas_limit: u32,
     address20_offset: *mut u8,
 ) -> ExitCode {
-    // TODO: "gas calculations"
-    // TODO: "call depth stack check >= 1024"
+    // check call stack limit
+    if ExecutionContext::contract_depth() >= CALL_STACK_LIMIT {
+        return ExitCode::CallDepthOverflow;
+    }
     // check write protection
     if ExecutionContext::contract_is_static() {
         return ExitCode::WriteProtection;
     }
+    // EIP-3860: deploy code length can't exceed max limit
+    if code_length as usize > WASM_MAX_INITCODE_SIZE {
+        return ExitCode::ContractSizeLimit;
+    }
+    let init_code = unsafe { &*ptr::slice_from_raw_parts(code_offset, code_length as usize) };
+    let (bytecode_wasm, args) = match decode_deploy_input(init_code) {
+        Ok(result) => result,
+        Err(exit_code) => return exit_code,
+    };
+    // deploy code and WASM input are charged before the translation, so binaries that can't pay
+    // for it are never translated
+    let mut charged_gas = 0u64;
+    if let Err(exit_code) = charge_gas(
+        &mut charged_gas,
+        deploy_cost(init_code.len(), bytecode_wasm.len()),
+        gas_limit,
+    ) {
+        return exit_code;
+    }
+    if let Err(err) = validate_wasm(bytecode_wasm, &WasmPolicy::default()) {
+        return err.into();
+    }
+
     // read value input and contract address
     let value32_slice = unsafe { &*core::ptr::slice_from_raw_parts(value32_offset, 32) };
     let salt32_slice = unsafe { &*core::ptr::slice_from_raw_parts(salt32_offset, 32) };
@@ -35,7 +65,6 @@ pub fn _wasm_create2(
     // load deployer and contract accounts
     let mut deployer_account = Account::new_from_jzkt(&caller_address);
 
-    let init_code = unsafe { &*ptr::slice_from_raw_parts(code_offset, code_length as usize) };
     let mut init_code_hash = B256::ZERO;
     LowLevelSDK::crypto_keccak256(
         init_code.as_ptr(),
@@ -57,23 +86,38 @@ pub fn _wasm_create2(
     }
 
     // translate WASM to rWASM
-    let bytecode_rwasm = wasm2rwasm(init_code).unwrap();
+    let bytecode_rwasm = match wasm2rwasm(bytecode_wasm) {
+        Ok(bytecode_rwasm) => bytecode_rwasm,
+        Err(exit_code) => return exit_code,
+    };
+    if let Err(exit_code) = charge_gas(
+        &mut charged_gas,
+        translation_cost(0, bytecode_rwasm.len()),
+        gas_limit,
+    ) {
+        return exit_code;
+    }
 
     // write deployer to the trie
     deployer_account.write_to_jzkt();
 
     // write contract to the trie
-    contract_account.update_source_bytecode(&init_code.into());
+    contract_account.update_source_bytecode(&bytecode_wasm.into());
     contract_account.update_rwasm_bytecode(&bytecode_rwasm.into());
-    rwasm_exec_hash(
-        contract_account.rwasm_bytecode_hash.as_slice(),
-        &[],
-        gas_limit,
-        true,
-    );
+    if let Err(exit_code) = exec_deploy_bytecode(
+        &contract_account,
+        caller_address,
+        value,
+        args,
+        gas_limit - charged_gas as u32,
+    ) {
+        return exit_code;
+    }
 
     // copy output address
-    unsafe { core::ptr::copy(deployed_contract_address.as_ptr(), address20_offset, 20) }
+    unsafe {
+        core::ptr::copy(deployed_contract_address.as_ptr(), address20_offset, 20);
+    }
 
     ExitCode::Ok
 }
@@ -0,0 +1,24 @@
+use crate::{account::Account, wasm::call::exec_wasm_bytecode};
+use fluentbase_sdk::evm::ExecutionContext;
+use fluentbase_types::{Address, Bytes, ExitCode};
+
+#[no_mangle]
+pub fn _wasm_delegatecall(
+    gas_limit: u32,
+    callee_address20_offset: *const u8,
+    args_offset: *const u8,
+    args_size: u32,
+) -> ExitCode {
+    // parse args
+    let args = unsafe { &*core::ptr::slice_from_raw_parts(args_offset, args_size as usize) };
+    // parse callee address, only its bytecode is used
+    let callee_address = Address::from_slice(unsafe {
+        &*core::ptr::slice_from_raw_parts(callee_address20_offset, 20)
+    });
+    let callee_account = Account::new_from_jzkt(&callee_address);
+    // address, caller and value are inherited from the current context, so there is no transfer
+    let mut contract_input = ExecutionContext::contract_input_full();
+    contract_input.contract_gas_limit = gas_limit as u64;
+    contract_input.contract_input = Bytes::from_static(args);
+    exec_wasm_bytecode(&callee_account, contract_input, gas_limit)
+}
@@ -0,0 +1,67 @@
+use fluentbase_sdk::{evm::ExecutionContext, LowLevelAPI, LowLevelSDK};
+use fluentbase_types::ExitCode;
+use revm_interpreter::gas::{initcode_cost, CODEDEPOSIT};
+use revm_primitives::RWASM_MAX_CODE_SIZE;
+
+/// EIP-3860: max size of the deploy code (WASM binary with constructor arguments)
+pub const WASM_MAX_INITCODE_SIZE: usize = 2 * RWASM_MAX_CODE_SIZE;
+
+/// Gas per 32-byte word of both WASM input and rWASM output of the translation
+pub const WASM_TRANSLATION_WORD_COST: u64 = 3;
+
+/// Gas charged before the WASM binary is translated: EIP-3860 cost of the deploy code and
+/// translation cost of the WASM input, the rWASM output is charged after the translation
+pub fn deploy_cost(initcode_len: usize, wasm_len: usize) -> u64 {
+    initcode_cost(initcode_len as u64) + translation_cost(wasm_len, 0)
+}
+
+pub fn translation_cost(wasm_len: usize, rwasm_len: usize) -> u64 {
+    let words = (wasm_len as u64).div_ceil(32) + (rwasm_len as u64).div_ceil(32);
+    WASM_TRANSLATION_WORD_COST * words
+}
+
+/// Cost of storing the WASM binary, its rWASM translation and immutable data, it's the same as EVM
+/// code deposit
+pub fn code_deposit_cost(len: usize) -> u64 {
+    CODEDEPOSIT * len as u64
+}
+
+/// Charges gas as fuel of the current execution, so it's reported to the caller together with
+/// the fuel consumed by instructions. `charged_gas` is the gas charged so far, it can't exceed
+/// `gas_limit`
+pub fn charge_gas(charged_gas: &mut u64, gas: u64, gas_limit: u32) -> Result<(), ExitCode> {
+    let total_gas = charged_gas.saturating_add(gas);
+    if total_gas > gas_limit as u64 {
+        return Err(ExitCode::OutOfFuel);
+    }
+    LowLevelSDK::sys_fuel(ExecutionContext::gas_to_fuel(gas));
+    *charged_gas = total_gas;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fluentbase_codec::Encoder;
+    use fluentbase_sdk::evm::ContractInput;
+
+    #[test]
+    fn test_deploy_cost() {
+        assert_eq!(translation_cost(0, 0), 0);
+        assert_eq!(translation_cost(1, 33), 3 * 3);
+        assert_eq!(deploy_cost(64, 1), 2 * 2 + 3);
+        assert_eq!(code_deposit_cost(10), 2000);
+    }
+
+    #[test]
+    fn test_charge_gas() {
+        LowLevelSDK::with_test_input(ContractInput::default().encode_to_vec(0));
+        let mut charged_gas = 0;
+        assert_eq!(charge_gas(&mut charged_gas, 60, 100), Ok(()));
+        assert_eq!(
+            charge_gas(&mut charged_gas, 50, 100),
+            Err(ExitCode::OutOfFuel)
+        );
+        assert_eq!(charged_gas, 60);
+    }
+}
@@ -0,0 +1,355 @@
+use fluentbase_sdk::{LowLevelAPI, LowLevelSDK};
+use fluentbase_types::{create_shared_import_linker, ExitCode, WASM_MAGIC};
+
+pub(crate) const WASM_VERSION: [u8; 4] = [0x01, 0x00, 0x00, 0x00];
+
+const SECTION_CUSTOM: u8 = 0;
+const SECTION_IMPORT: u8 = 2;
+const SECTION_TABLE: u8 = 4;
+const SECTION_MEMORY: u8 = 5;
+const SECTION_EXPORT: u8 = 7;
+const SECTION_START: u8 = 8;
+const SECTION_DATA_COUNT: u8 = 12;
+
+const EXTERNAL_FUNC: u8 = 0x00;
+const EXTERNAL_TABLE: u8 = 0x01;
+const EXTERNAL_MEMORY: u8 = 0x02;
+const EXTERNAL_GLOBAL: u8 = 0x03;
+
+/// Rules a WASM contract must follow to be deployed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WasmPolicy {
+    /// The only module contracts are allowed to import functions from, only functions of the
+    /// shared import linker can be imported
+    pub import_module: &'static str,
+    /// Function exports every contract must have
+    pub required_exports: &'static [&'static str],
+    /// Max number of 64KiB pages a memory can start with or declare as its maximum
+    pub max_memory_pages: u32,
+    /// Max number of elements a table can start with or declare as its maximum
+    pub max_table_size: u32,
+    pub allow_start_function: bool,
+}
+
+impl WasmPolicy {
+    pub const fn new() -> Self {
+        Self {
+            import_module: "fluentbase_v1alpha",
+            required_exports: &["deploy", "main"],
+            max_memory_pages: 1024,
+            max_table_size: 1024,
+            allow_start_function: false,
+        }
+    }
+
+    /// Policy for runtime code returned by the deploy function, it's never deployed again so
+    /// only `main` is required
+    pub const fn runtime() -> Self {
+        Self {
+            required_exports: &["main"],
+            ..Self::new()
+        }
+    }
+}
+
+impl Default for WasmPolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reason a WASM contract is rejected, every reason is reported as
+/// [`ExitCode::CompilationError`] with the reason written into the output
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WasmValidationError {
+    MalformedBinary,
+    ForbiddenImport,
+    MissingExport,
+    MemoryTooLarge,
+    TableTooLarge,
+    StartFunction,
+}
+
+impl WasmValidationError {
+    pub fn reason(&self) -> &'static str {
+        match self {
+            WasmValidationError::MalformedBinary => "malformed wasm binary",
+            WasmValidationError::ForbiddenImport => "forbidden wasm import",
+            WasmValidationError::MissingExport => "missing wasm export",
+            WasmValidationError::MemoryTooLarge => "wasm memory is too large",
+            WasmValidationError::TableTooLarge => "wasm table is too large",
+            WasmValidationError::StartFunction => "wasm start function isn't allowed",
+        }
+    }
+}
+
+impl From<WasmValidationError> for ExitCode {
+    fn from(err: WasmValidationError) -> Self {
+        LowLevelSDK::sys_write(err.reason().as_bytes());
+        ExitCode::CompilationError
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    fn read_u8(&mut self) -> Result<u8, WasmValidationError> {
+        let byte = *self
+            .data
+            .get(self.pos)
+            .ok_or(WasmValidationError::MalformedBinary)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, WasmValidationError> {
+        let mut result = 0u32;
+        for i in 0..5 {
+            let byte = self.read_u8()?;
+            result |= ((byte & 0x7f) as u32) << (i * 7);
+            if byte & 0x80 == 0 {
+                return Ok(result);
+            }
+        }
+        Err(WasmValidationError::MalformedBinary)
+    }
+
+    fn read_bytes(&mut self, len: usize) -> Result<&'a [u8], WasmValidationError> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(WasmValidationError::MalformedBinary)?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn read_name(&mut self) -> Result<&'a [u8], WasmValidationError> {
+        let len = self.read_u32()? as usize;
+        self.read_bytes(len)
+    }
+
+    /// Returns `(min, max)` of table or memory limits
+    fn read_limits(&mut self) -> Result<(u32, Option<u32>), WasmValidationError> {
+        match self.read_u8()? {
+            0x00 => Ok((self.read_u32()?, None)),
+            0x01 => Ok((self.read_u32()?, Some(self.read_u32()?))),
+            _ => Err(WasmValidationError::MalformedBinary),
+        }
+    }
+}
+
+fn check_limits(
+    (min, max): (u32, Option<u32>),
+    limit: u32,
+    err: WasmValidationError,
+) -> Result<(), WasmValidationError> {
+    if min > limit || max.unwrap_or_default() > limit {
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// Checks imports, exports, memories, tables and the start function of a WASM binary against the
+/// policy. Instruction level checks (e.g. no floats) are done by the rWASM compiler.
+pub fn validate_wasm(wasm_binary: &[u8], policy: &WasmPolicy) -> Result<(), WasmValidationError> {
+    let mut reader = Reader {
+        data: wasm_binary,
+        pos: 0,
+    };
+    if reader.read_bytes(4)? != WASM_MAGIC || reader.read_bytes(4)? != WASM_VERSION {
+        return Err(WasmValidationError::MalformedBinary);
+    }
+    let shared_imports: [(&str, &str, u32, u32); 22] = create_shared_import_linker();
+    let mut exports_found = 0usize;
+    while !reader.is_empty() {
+        let section_id = reader.read_u8()?;
+        let section_len = reader.read_u32()? as usize;
+        let mut section = Reader {
+            data: reader.read_bytes(section_len)?,
+            pos: 0,
+        };
+        match section_id {
+            SECTION_IMPORT => {
+                for _ in 0..section.read_u32()? {
+                    let module = section.read_name()?;
+                    let name = section.read_name()?;
+                    if module != policy.import_module.as_bytes() {
+                        return Err(WasmValidationError::ForbiddenImport);
+                    }
+                    // sovereign only functions (e.g. `_jzkt_update`) can't be imported
+                    if !shared_imports.iter().any(|(_, v, ..)| v.as_bytes() == name) {
+                        return Err(WasmValidationError::ForbiddenImport);
+                    }
+                    match section.read_u8()? {
+                        EXTERNAL_FUNC => {
+                            section.read_u32()?;
+                        }
+                        // contracts can't import state that isn't managed by the runtime
+                        EXTERNAL_TABLE | EXTERNAL_MEMORY | EXTERNAL_GLOBAL => {
+                            return Err(WasmValidationError::ForbiddenImport)
+                        }
+                        _ => return Err(WasmValidationError::MalformedBinary),
+                    }
+                }
+            }
+            SECTION_TABLE => {
+                for _ in 0..section.read_u32()? {
+                    section.read_u8()?;
+                    check_limits(
+                        section.read_limits()?,
+                        policy.max_table_size,
+                        WasmValidationError::TableTooLarge,
+                    )?;
+                }
+            }
+            SECTION_MEMORY => {
+                for _ in 0..section.read_u32()? {
+                    check_limits(
+                        section.read_limits()?,
+                        policy.max_memory_pages,
+                        WasmValidationError::MemoryTooLarge,
+                    )?;
+                }
+            }
+            SECTION_EXPORT => {
+                for _ in 0..section.read_u32()? {
+                    let name = section.read_name()?;
+                    let kind = section.read_u8()?;
+                    section.read_u32()?;
+                    if kind == EXTERNAL_FUNC
+                        && policy.required_exports.iter().any(|v| v.as_bytes() == name)
+                    {
+                        exports_found += 1;
+                    }
+                }
+            }
+            SECTION_START if !policy.allow_start_function => {
+                return Err(WasmValidationError::StartFunction);
+            }
+            SECTION_CUSTOM..=SECTION_DATA_COUNT => {}
+            _ => return Err(WasmValidationError::MalformedBinary),
+        }
+    }
+    if exports_found < policy.required_exports.len() {
+        return Err(WasmValidationError::MissingExport);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+
+    fn section(id: u8, payload: &[u8]) -> Vec<u8> {
+        let mut result = vec![id, payload.len() as u8];
+        result.extend_from_slice(payload);
+        result
+    }
+
+    fn module(sections: &[Vec<u8>]) -> Vec<u8> {
+        let mut result = Vec::from(WASM_MAGIC);
+        result.extend_from_slice(&WASM_VERSION);
+        sections.iter().for_each(|v| result.extend_from_slice(v));
+        result
+    }
+
+    fn exports() -> Vec<u8> {
+        let mut payload = vec![2, 6];
+        payload.extend_from_slice(b"deploy");
+        payload.extend_from_slice(&[EXTERNAL_FUNC, 0, 4]);
+        payload.extend_from_slice(b"main");
+        payload.extend_from_slice(&[EXTERNAL_FUNC, 1]);
+        section(SECTION_EXPORT, &payload)
+    }
+
+    fn import(module_name: &[u8], name: &[u8], kind: u8) -> Vec<u8> {
+        let mut payload = vec![1, module_name.len() as u8];
+        payload.extend_from_slice(module_name);
+        payload.push(name.len() as u8);
+        payload.extend_from_slice(name);
+        payload.extend_from_slice(&[kind, 0]);
+        section(SECTION_IMPORT, &payload)
+    }
+
+    #[test]
+    fn test_validate_wasm() {
+        let policy = WasmPolicy::default();
+        let valid = module(&[
+            import(b"fluentbase_v1alpha", b"_sys_halt", EXTERNAL_FUNC),
+            section(SECTION_MEMORY, &[1, 0x01, 1, 16]),
+            exports(),
+        ]);
+        assert_eq!(validate_wasm(&valid, &policy), Ok(()));
+        assert_eq!(
+            validate_wasm(&valid[..valid.len() - 1], &policy),
+            Err(WasmValidationError::MalformedBinary)
+        );
+        assert_eq!(
+            validate_wasm(
+                &module(&[import(b"env", b"_sys_halt", EXTERNAL_FUNC), exports()]),
+                &policy
+            ),
+            Err(WasmValidationError::ForbiddenImport)
+        );
+        for name in [&b"_jzkt_update"[..], b"_jzkt_commit", b"_unknown"] {
+            assert_eq!(
+                validate_wasm(
+                    &module(&[
+                        import(b"fluentbase_v1alpha", name, EXTERNAL_FUNC),
+                        exports()
+                    ]),
+                    &policy
+                ),
+                Err(WasmValidationError::ForbiddenImport)
+            );
+        }
+        assert_eq!(
+            validate_wasm(&module(&[section(SECTION_MEMORY, &[1, 0x00, 1])]), &policy),
+            Err(WasmValidationError::MissingExport)
+        );
+        assert_eq!(
+            validate_wasm(
+                &module(&[section(SECTION_MEMORY, &[1, 0x00, 0x80, 0x10]), exports()]),
+                &policy
+            ),
+            Err(WasmValidationError::MemoryTooLarge)
+        );
+        assert_eq!(
+            validate_wasm(
+                &module(&[
+                    section(SECTION_TABLE, &[1, 0x70, 0x00, 0x81, 0x08]),
+                    exports()
+                ]),
+                &policy
+            ),
+            Err(WasmValidationError::TableTooLarge)
+        );
+        assert_eq!(
+            validate_wasm(&module(&[exports(), section(SECTION_START, &[0])]), &policy),
+            Err(WasmValidationError::StartFunction)
+        );
+    }
+
+    #[test]
+    fn test_runtime_policy_requires_main_only() {
+        let mut payload = vec![1, 4];
+        payload.extend_from_slice(b"main");
+        payload.extend_from_slice(&[EXTERNAL_FUNC, 0]);
+        let runtime = module(&[section(SECTION_EXPORT, &payload)]);
+        assert_eq!(validate_wasm(&runtime, &WasmPolicy::runtime()), Ok(()));
+        assert_eq!(
+            validate_wasm(&runtime, &WasmPolicy::default()),
+            Err(WasmValidationError::MissingExport)
+        );
+    }
+}
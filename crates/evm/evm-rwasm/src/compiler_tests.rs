@@ -406,13 +406,13 @@ fn run_test(
     contract_input.block_timestamp = u64::from_be_bytes(HOST_TIMESTAMP);
     contract_input.block_difficulty = u64::from_be_bytes(HOST_ENV_DIFFICULTY);
     contract_input.contract_bytecode = Bytes::copy_from_slice(CONTRACT_BYTECODE);
-    // contract_input.tx_blob_gas_price = u64::from_be_bytes(HOST_ENV_BLOBBASEFEE);
+    contract_input.tx_blob_gas_price = U256::from(u64::from_be_bytes(HOST_ENV_BLOBBASEFEE));
     contract_input.tx_gas_price = U256::from_be_bytes(HOST_ENV_GASPRICE);
     contract_input.tx_caller = Address::new(HOST_ENV_ORIGIN);
-    // contract_input.tx_blob_hashes = HOST_ENV_BLOB_HASHES
-    //     .iter()
-    //     .map(|v| B256::from_slice(v))
-    //     .collect();
+    contract_input.tx_blob_hashes = HOST_ENV_BLOB_HASHES
+        .iter()
+        .map(|v| B256::from_slice(v))
+        .collect();
     let mut account_db = InMemoryAccountDb::default();
 
     account_db.update_account(&CALLER_ADDRESS, &CALLER_ACCOUNT);
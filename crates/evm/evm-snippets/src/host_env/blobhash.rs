@@ -10,13 +10,11 @@ pub fn host_env_blobhash() {
 
     let idx = u256_be_to_u64tuple_le(idx);
 
-    stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, u256_from_be_slice(&[]));
-
-    // let hashes = ExecutionContext::tx_blob_hashes();
-    // if idx.1 > 0 || idx.2 > 0 || idx.3 > 0 || idx.0 >= hashes.len() as u64 {
-    //     stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, u256_from_be_slice(&[]));
-    //     return;
-    // }
-    // let hash = hashes[idx.0 as usize].0;
-    // stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, hash);
+    let hashes = ExecutionContext::tx_blob_hashes();
+    if idx.1 > 0 || idx.2 > 0 || idx.3 > 0 || idx.0 >= hashes.len() as u64 {
+        stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, u256_from_be_slice(&[]));
+        return;
+    }
+    let hash = hashes[idx.0 as usize].0;
+    stack_push_u256(SP_BASE_MEM_OFFSET_DEFAULT, hash);
 }
@@ -0,0 +1,309 @@
+use crate::{DEFAULT_SPEC_ID, EVM};
+use alloy_rlp::{BufMut, Encodable, Header};
+use eth_trie::{EthTrie, MemoryDB, Trie, TrieError};
+use fluentbase_runtime::IJournaledTrie;
+use fluentbase_sdk::LowLevelSDK;
+use fluentbase_types::{ExitCode, FuelSchedule};
+use revm_primitives::{
+    alloy_primitives::Bloom,
+    BlockEnv,
+    Bytes,
+    CfgEnv,
+    EVMError,
+    Env,
+    ExecutionResult,
+    InvalidTransaction,
+    Log,
+    SpecId,
+    TxEnv,
+    B256,
+};
+use std::{cell::RefCell, rc::Rc, sync::Arc};
+
+/// Transaction of the block
+#[derive(Debug, Clone)]
+pub struct BlockTransaction {
+    /// Environment of the transaction that is used for the execution
+    pub tx: TxEnv,
+    /// EIP-2718 encoded signed transaction, it's used to compute transactions root
+    pub encoded: Bytes,
+}
+
+/// Ethereum-style transaction receipt
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Receipt {
+    /// EIP-2718 transaction type
+    pub tx_type: u8,
+    /// Whether transaction is executed successfully
+    pub success: bool,
+    /// Gas used by the transaction and all previous transactions in the block
+    pub cumulative_gas_used: u64,
+    pub logs: Vec<Log>,
+    pub logs_bloom: Bloom,
+}
+
+impl Receipt {
+    pub fn new(tx_type: u8, success: bool, cumulative_gas_used: u64, logs: Vec<Log>) -> Self {
+        let logs_bloom = logs_bloom(&logs);
+        Self {
+            tx_type,
+            success,
+            cumulative_gas_used,
+            logs,
+            logs_bloom,
+        }
+    }
+
+    fn payload_length(&self) -> usize {
+        self.success.length()
+            + self.cumulative_gas_used.length()
+            + self.logs_bloom.length()
+            + self.logs.length()
+    }
+
+    /// Encodes receipt in the same way as it's stored in the receipts trie, typed receipts are
+    /// prefixed with transaction type (EIP-2718)
+    pub fn encode_2718(&self, out: &mut dyn BufMut) {
+        if self.tx_type != 0 {
+            out.put_u8(self.tx_type);
+        }
+        Header {
+            list: true,
+            payload_length: self.payload_length(),
+        }
+        .encode(out);
+        self.success.encode(out);
+        self.cumulative_gas_used.encode(out);
+        self.logs_bloom.encode(out);
+        self.logs.encode(out);
+    }
+}
+
+/// Result of the block execution
+#[derive(Debug, Clone)]
+pub struct BlockResult {
+    pub receipts: Vec<Receipt>,
+    pub receipts_root: B256,
+    pub transactions_root: B256,
+    pub logs_bloom: Bloom,
+    pub gas_used: u64,
+    /// JZKT root after execution of all transactions
+    pub state_root: B256,
+    /// Indices of rejected transactions with the reasons, their changes are reverted and their
+    /// receipts are failed w/o any gas used
+    pub rejected_transactions: Vec<(usize, EVMError<ExitCode>)>,
+}
+
+/// Executes transactions of the block one by one against the same journaled trie, every
+/// transaction is executed under its own checkpoint, so an invalid transaction is reverted alone.
+/// The state is committed once all transactions are executed.
+pub struct BlockExecutor {
+    cfg: CfgEnv,
+    block: BlockEnv,
+    spec_id: SpecId,
+    fuel_schedule: FuelSchedule,
+    jzkt: Rc<RefCell<dyn IJournaledTrie>>,
+}
+
+impl BlockExecutor {
+    pub fn new(cfg: CfgEnv, block: BlockEnv, jzkt: Rc<RefCell<dyn IJournaledTrie>>) -> Self {
+        Self {
+            cfg,
+            block,
+            spec_id: DEFAULT_SPEC_ID,
+            fuel_schedule: Default::default(),
+            jzkt,
+        }
+    }
+
+    /// Sets spec that is used for all transactions of the block
+    pub fn with_spec_id(mut self, spec_id: SpecId) -> Self {
+        self.spec_id = spec_id;
+        self
+    }
+
+    /// Sets gas schedule of rWASM execution that is used for all transactions of the block
+    pub fn with_fuel_schedule(mut self, fuel_schedule: FuelSchedule) -> Self {
+        self.fuel_schedule = fuel_schedule;
+        self
+    }
+
+    /// Executes all transactions, invalid transactions get failed receipts and don't affect other
+    /// transactions of the block. Changes made by the block are reverted only if the block result
+    /// can't be computed
+    pub fn execute(
+        &mut self,
+        transactions: &[BlockTransaction],
+    ) -> Result<BlockResult, EVMError<ExitCode>> {
+        LowLevelSDK::with_jzkt(self.jzkt.clone());
+        let checkpoint = self.jzkt.borrow_mut().checkpoint();
+        let result = self.execute_transactions(transactions);
+        if result.is_err() {
+            self.jzkt.borrow_mut().rollback(checkpoint);
+        }
+        result
+    }
+
+    fn execute_transactions(
+        &mut self,
+        transactions: &[BlockTransaction],
+    ) -> Result<BlockResult, EVMError<ExitCode>> {
+        let block_gas_limit = self.block.gas_limit.saturating_to::<u64>();
+        let mut receipts = Vec::with_capacity(transactions.len());
+        let mut cumulative_gas_used = 0u64;
+        let mut rejected_transactions = Vec::new();
+        for (i, transaction) in transactions.iter().enumerate() {
+            let checkpoint = self.jzkt.borrow_mut().checkpoint();
+            match self.execute_transaction(&transaction.tx, block_gas_limit - cumulative_gas_used) {
+                Ok(result) => {
+                    cumulative_gas_used += result.gas_used();
+                    receipts.push(Receipt::new(
+                        tx_type(&transaction.tx),
+                        result.is_success(),
+                        cumulative_gas_used,
+                        result.logs().to_vec(),
+                    ));
+                }
+                Err(err) => {
+                    // only changes of the rejected transaction are reverted
+                    self.jzkt.borrow_mut().rollback(checkpoint);
+                    receipts.push(Receipt::new(
+                        tx_type(&transaction.tx),
+                        false,
+                        cumulative_gas_used,
+                        vec![],
+                    ));
+                    rejected_transactions.push((i, err));
+                }
+            }
+        }
+        let logs_bloom = receipts.iter().fold(Bloom::ZERO, |mut bloom, receipt| {
+            bloom.accrue_bloom(&receipt.logs_bloom);
+            bloom
+        });
+        let receipts_root = ordered_trie_root(receipts.iter().map(|receipt| {
+            let mut encoded = Vec::new();
+            receipt.encode_2718(&mut encoded);
+            encoded
+        }))
+        .map_err(|err| EVMError::Custom(err.to_string()))?;
+        let transactions_root =
+            ordered_trie_root(transactions.iter().map(|tx| tx.encoded.to_vec()))
+                .map_err(|err| EVMError::Custom(err.to_string()))?;
+        // changes are committed at the very end, because they can't be reverted after it
+        self.commit().map_err(EVMError::Database)?;
+        let state_root = B256::from(self.jzkt.borrow().compute_root());
+        Ok(BlockResult {
+            receipts,
+            receipts_root,
+            transactions_root,
+            logs_bloom,
+            gas_used: cumulative_gas_used,
+            state_root,
+            rejected_transactions,
+        })
+    }
+
+    fn execute_transaction(
+        &self,
+        tx: &TxEnv,
+        gas_left: u64,
+    ) -> Result<ExecutionResult, EVMError<ExitCode>> {
+        // transaction can't use more gas than left in the block
+        if tx.gas_limit > gas_left {
+            return Err(InvalidTransaction::CallerGasLimitMoreThanBlock.into());
+        }
+        let mut evm = EVM::with_env_and_spec_id(
+            Env {
+                cfg: self.cfg.clone(),
+                block: self.block.clone(),
+                tx: tx.clone(),
+            },
+            self.spec_id,
+        )
+        .with_fuel_schedule(self.fuel_schedule.clone());
+        Ok(evm.transact()?.result)
+    }
+
+    fn commit(&self) -> Result<(), ExitCode> {
+        let mut jzkt = self.jzkt.borrow_mut();
+        // journal can't be committed w/o changes
+        if jzkt.events().is_empty() {
+            return Ok(());
+        }
+        jzkt.commit()?;
+        Ok(())
+    }
+}
+
+/// Computes bloom filter of the logs
+pub fn logs_bloom(logs: &[Log]) -> Bloom {
+    let mut bloom = Bloom::ZERO;
+    for log in logs {
+        bloom.accrue_log(log);
+    }
+    bloom
+}
+
+/// Computes root of the Merkle-Patricia trie where keys are RLP encoded indices of the items
+pub fn ordered_trie_root<I: IntoIterator<Item = Vec<u8>>>(items: I) -> Result<B256, TrieError> {
+    let mut trie = EthTrie::new(Arc::new(MemoryDB::new(true)));
+    for (index, item) in items.into_iter().enumerate() {
+        trie.insert(&alloy_rlp::encode(index), &item)?;
+    }
+    let root = trie.root_hash()?;
+    Ok(B256::from_slice(root.as_bytes()))
+}
+
+/// Infers EIP-2718 transaction type from the fields that are used by the transaction
+fn tx_type(tx: &TxEnv) -> u8 {
+    if !tx.blob_hashes.is_empty() {
+        3
+    } else if tx.gas_priority_fee.is_some() {
+        2
+    } else if !tx.access_list.is_empty() {
+        1
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use revm_primitives::{address, b256, hex, Address};
+
+    #[test]
+    fn test_empty_trie_root() {
+        assert_eq!(
+            ordered_trie_root(Vec::new()).unwrap(),
+            b256!("56e81f171bcc55a6ff8345e692c0f86e5b48e01b996cadc001622fb5e363b421")
+        );
+    }
+
+    #[test]
+    fn test_receipt_encoding() {
+        let receipt = Receipt::new(0, true, 21000, vec![]);
+        let mut encoded = Vec::new();
+        receipt.encode_2718(&mut encoded);
+        // list header (3) + status (1) + gas (1 + 2) + bloom (3 + 256) + logs (1)
+        assert_eq!(encoded.len(), 3 + 1 + 3 + 259 + 1);
+        assert_eq!(encoded[0..3], hex!("f90108"));
+        assert_eq!(encoded[3], 0x01);
+        // typed receipts are prefixed with type
+        let receipt = Receipt::new(2, false, 21000, vec![]);
+        let mut encoded = Vec::new();
+        receipt.encode_2718(&mut encoded);
+        assert_eq!(encoded[0], 0x02);
+        assert_eq!(encoded[4], 0x80);
+    }
+
+    #[test]
+    fn test_logs_bloom() {
+        const ADDRESS: Address = address!("0000000000000000000000000000000000000001");
+        let log = Log::new_unchecked(ADDRESS, vec![B256::with_last_byte(1)], Bytes::new());
+        let bloom = logs_bloom(&[log.clone()]);
+        assert!(bloom.contains_log(&log));
+        assert!(!bloom.contains_raw_log(ADDRESS, &[B256::with_last_byte(2)]));
+    }
+}
@@ -6,4 +6,7 @@ pub struct EVMData<'a> {
     /// EVM Environment contains all the information about config, block and transaction that
     /// evm needs.
     pub env: &'a mut Env,
+    /// Journal checkpoint taken at the beginning of the transaction, it's used to collect state
+    /// changes and logs made by the transaction.
+    pub journal_checkpoint: u64,
 }
@@ -1,6 +1,10 @@
-use crate::r#impl::{EVMImpl, Transact};
-use alloc::boxed::Box;
-use fluentbase_types::ExitCode;
+use crate::{
+    inspector::Inspector,
+    r#impl::{EVMImpl, Transact},
+};
+use alloc::{boxed::Box, rc::Rc};
+use fluentbase_genesis::ChainConfig;
+use fluentbase_types::{ExitCode, FuelSchedule};
 use revm_primitives::{specification, EVMError, EVMResult, Env, SpecId};
 
 /// Struct that takes Database and enabled transact to update state directly to database.
@@ -35,8 +39,15 @@ use revm_primitives::{specification, EVMError, EVMResult, Env, SpecId};
 #[derive(Clone, Debug)]
 pub struct EVM {
     pub env: Env,
+    /// Spec that is used for both transaction processing and EVM interpreter inside ECL
+    pub spec_id: SpecId,
+    /// Gas schedule of rWASM execution, it's shared with all nested executions
+    pub fuel_schedule: Rc<FuelSchedule>,
 }
 
+/// Spec that is used if it's not specified explicitly
+pub const DEFAULT_SPEC_ID: SpecId = SpecId::CANCUN;
+
 pub fn new() -> EVM {
     EVM::new()
 }
@@ -50,18 +61,30 @@ impl Default for EVM {
 impl EVM {
     /// Do checks that could make transaction fail before call/create
     pub fn preverify_transaction(&mut self) -> Result<(), EVMError<ExitCode>> {
-        evm_inner(&mut self.env, SpecId::CANCUN).preverify_transaction()
+        evm_inner(&mut self.env, self.spec_id, self.fuel_schedule.clone()).preverify_transaction()
     }
 
     /// Skip preverification steps and execute transaction without writing to DB, return change
     /// state.
     pub fn transact_preverified(&mut self) -> EVMResult<ExitCode> {
-        evm_inner(&mut self.env, SpecId::CANCUN).transact_preverified()
+        evm_inner(&mut self.env, self.spec_id, self.fuel_schedule.clone()).transact_preverified()
     }
 
     /// Execute transaction without writing to DB, return change state.
     pub fn transact(&mut self) -> EVMResult<ExitCode> {
-        evm_inner(&mut self.env, SpecId::CANCUN).transact()
+        evm_inner(&mut self.env, self.spec_id, self.fuel_schedule.clone()).transact()
+    }
+
+    /// Execute transaction with the inspector, hooks are called for the transaction's call or
+    /// create frame.
+    pub fn inspect<INSP: Inspector>(&mut self, mut inspector: INSP) -> EVMResult<ExitCode> {
+        inspect_inner(
+            &mut self.env,
+            self.spec_id,
+            self.fuel_schedule.clone(),
+            &mut inspector,
+        )
+        .transact()
     }
 }
 
@@ -73,14 +96,113 @@ impl EVM {
 
     /// Creates a new [EVM] instance with the given environment.
     pub fn with_env(env: Env) -> Self {
-        Self { env }
+        Self::with_env_and_spec_id(env, DEFAULT_SPEC_ID)
     }
+
+    /// Creates a new [EVM] instance with the given environment and spec.
+    pub fn with_env_and_spec_id(env: Env, spec_id: SpecId) -> Self {
+        Self {
+            env,
+            spec_id,
+            fuel_schedule: Default::default(),
+        }
+    }
+
+    /// Sets gas schedule of rWASM execution
+    pub fn with_fuel_schedule(mut self, fuel_schedule: FuelSchedule) -> Self {
+        self.fuel_schedule = Rc::new(fuel_schedule);
+        self
+    }
+
+    /// Creates a new [EVM] instance with the spec that is active for the environment's block
+    /// according to the chain config.
+    pub fn with_chain_config(env: Env, chain_config: &ChainConfig) -> Self {
+        let spec_id = spec_id_from_chain_config(
+            chain_config,
+            env.block.number.as_limbs()[0],
+            env.block.timestamp.as_limbs()[0],
+        );
+        Self::with_env_and_spec_id(env, spec_id)
+    }
+}
+
+/// Returns the latest spec that is activated at the given block number and timestamp. Forks
+/// before the merge are activated by block number, forks after the merge by timestamp.
+pub fn spec_id_from_chain_config(
+    chain_config: &ChainConfig,
+    block_number: u64,
+    timestamp: u64,
+) -> SpecId {
+    let block_activated = |block: Option<u64>| block.map_or(false, |block| block <= block_number);
+    let time_activated = |time: Option<u64>| time.map_or(false, |time| time <= timestamp);
+    if time_activated(chain_config.cancun_time) {
+        SpecId::CANCUN
+    } else if time_activated(chain_config.shanghai_time) {
+        SpecId::SHANGHAI
+    } else if block_activated(chain_config.merge_netsplit_block)
+        || chain_config.terminal_total_difficulty_passed
+    {
+        SpecId::MERGE
+    } else if block_activated(chain_config.gray_glacier_block) {
+        SpecId::GRAY_GLACIER
+    } else if block_activated(chain_config.arrow_glacier_block) {
+        SpecId::ARROW_GLACIER
+    } else if block_activated(chain_config.london_block) {
+        SpecId::LONDON
+    } else if block_activated(chain_config.berlin_block) {
+        SpecId::BERLIN
+    } else if block_activated(chain_config.muir_glacier_block) {
+        SpecId::MUIR_GLACIER
+    } else if block_activated(chain_config.istanbul_block) {
+        SpecId::ISTANBUL
+    } else if block_activated(chain_config.petersburg_block) {
+        SpecId::PETERSBURG
+    } else if block_activated(chain_config.constantinople_block) {
+        SpecId::CONSTANTINOPLE
+    } else if block_activated(chain_config.byzantium_block) {
+        SpecId::BYZANTIUM
+    } else if block_activated(chain_config.eip158_block) {
+        SpecId::SPURIOUS_DRAGON
+    } else if block_activated(chain_config.eip150_block) {
+        SpecId::TANGERINE
+    } else if block_activated(chain_config.dao_fork_block) {
+        SpecId::DAO_FORK
+    } else if block_activated(chain_config.homestead_block) {
+        SpecId::HOMESTEAD
+    } else {
+        SpecId::FRONTIER
+    }
+}
+
+pub fn evm_inner<'a>(
+    env: &'a mut Env,
+    spec_id: SpecId,
+    fuel_schedule: Rc<FuelSchedule>,
+) -> Box<dyn Transact<ExitCode> + 'a> {
+    create_evm_impl(env, spec_id, fuel_schedule, None)
+}
+
+pub fn inspect_inner<'a>(
+    env: &'a mut Env,
+    spec_id: SpecId,
+    fuel_schedule: Rc<FuelSchedule>,
+    inspector: &'a mut dyn Inspector,
+) -> Box<dyn Transact<ExitCode> + 'a> {
+    create_evm_impl(env, spec_id, fuel_schedule, Some(inspector))
 }
 
-pub fn evm_inner<'a>(env: &'a mut Env, spec_id: SpecId) -> Box<dyn Transact<ExitCode> + 'a> {
+fn create_evm_impl<'a>(
+    env: &'a mut Env,
+    spec_id: SpecId,
+    fuel_schedule: Rc<FuelSchedule>,
+    inspector: Option<&'a mut dyn Inspector>,
+) -> Box<dyn Transact<ExitCode> + 'a> {
     macro_rules! create_evm {
         ($spec:ident) => {
-            Box::new(EVMImpl::<'a, $spec>::new(env)) as Box<dyn Transact<ExitCode> + 'a>
+            Box::new(
+                EVMImpl::<'a, $spec>::new_with_inspector(env, inspector)
+                    .with_fuel_schedule(fuel_schedule),
+            ) as Box<dyn Transact<ExitCode> + 'a>
         };
     }
 
@@ -1,5 +1,8 @@
 //! EVM gas calculation utilities.
 
+use alloc::vec::Vec;
+use revm_primitives::{Address, Spec, SpecId::*, U256};
+
 /// Represents the state of gas during execution.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
 pub struct Gas {
@@ -111,3 +114,99 @@ impl Gas {
         true
     }
 }
+
+pub const TRANSACTION_ZERO_DATA: u64 = 4;
+pub const TRANSACTION_NON_ZERO_DATA_INIT: u64 = 16;
+pub const TRANSACTION_NON_ZERO_DATA_FRONTIER: u64 = 68;
+pub const ACCESS_LIST_ADDRESS: u64 = 2400;
+pub const ACCESS_LIST_STORAGE_KEY: u64 = 1900;
+pub const INITCODE_WORD_COST: u64 = 2;
+
+/// EIP-3860: Limit and meter initcode
+#[inline]
+pub const fn initcode_cost(len: u64) -> u64 {
+    INITCODE_WORD_COST * len.div_ceil(32)
+}
+
+/// Initial gas that is deducted for transaction to be included.
+///
+/// It includes the base stipend, calldata cost, access list cost and initcode cost.
+pub fn initial_tx_gas<SPEC: Spec>(
+    input: &[u8],
+    is_create: bool,
+    access_list: &[(Address, Vec<U256>)],
+) -> u64 {
+    let mut initial_gas = 0;
+    let zero_data_len = input.iter().filter(|v| **v == 0).count() as u64;
+    let non_zero_data_len = input.len() as u64 - zero_data_len;
+
+    // initdate stipend
+    initial_gas += zero_data_len * TRANSACTION_ZERO_DATA;
+    // EIP-2028: Transaction data gas cost reduction
+    initial_gas += non_zero_data_len
+        * if SPEC::enabled(ISTANBUL) {
+            TRANSACTION_NON_ZERO_DATA_INIT
+        } else {
+            TRANSACTION_NON_ZERO_DATA_FRONTIER
+        };
+
+    // get number of access list account and storages.
+    if SPEC::enabled(BERLIN) {
+        let accessed_slots = access_list
+            .iter()
+            .fold(0, |slot_count, (_, slots)| slot_count + slots.len() as u64);
+        initial_gas += access_list.len() as u64 * ACCESS_LIST_ADDRESS;
+        initial_gas += accessed_slots * ACCESS_LIST_STORAGE_KEY;
+    }
+
+    // base stipend
+    initial_gas += if is_create && SPEC::enabled(HOMESTEAD) {
+        // EIP-2: Homestead Hard-fork Changes
+        53000
+    } else {
+        21000
+    };
+
+    // EIP-3860: Limit and meter initcode
+    if SPEC::enabled(SHANGHAI) && is_create {
+        initial_gas += initcode_cost(input.len() as u64)
+    }
+
+    initial_gas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use revm_primitives::{address, BerlinSpec, CancunSpec};
+
+    #[test]
+    fn test_initial_tx_gas() {
+        // plain transfer
+        assert_eq!(initial_tx_gas::<CancunSpec>(&[], false, &[]), 21000);
+        // calldata cost
+        assert_eq!(
+            initial_tx_gas::<CancunSpec>(&[0, 1, 2], false, &[]),
+            21000 + 4 + 2 * 16
+        );
+        // access list cost
+        let access_list = vec![(
+            address!("0000000000000000000000000000000000000001"),
+            vec![U256::from(1), U256::from(2)],
+        )];
+        assert_eq!(
+            initial_tx_gas::<CancunSpec>(&[], false, &access_list),
+            21000 + 2400 + 2 * 1900
+        );
+        // create cost with initcode words
+        assert_eq!(
+            initial_tx_gas::<CancunSpec>(&[0u8; 33], true, &[]),
+            53000 + 33 * 4 + 2 * 2
+        );
+        // initcode isn't metered before Shanghai
+        assert_eq!(
+            initial_tx_gas::<BerlinSpec>(&[0u8; 33], true, &[]),
+            53000 + 33 * 4
+        );
+    }
+}
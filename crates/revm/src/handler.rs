@@ -222,7 +222,7 @@ mod mainnet {
     /// Main return handle, returns the output of the transaction.
     #[inline]
     pub(crate) fn main_return(
-        _data: &mut EVMData<'_>,
+        data: &mut EVMData<'_>,
         call_result: i32,
         output: Output,
         gas: &Gas,
@@ -231,12 +231,20 @@ mod mainnet {
         let gas_refunded = gas.refunded() as u64;
         let final_gas_used = gas.spend() - gas_refunded;
 
+        #[cfg(feature = "std")]
+        let (state, logs) = crate::state::journal_state_and_logs(data.journal_checkpoint);
+        #[cfg(not(feature = "std"))]
+        let (state, logs) = {
+            let _ = data;
+            (State::new(), vec![])
+        };
+
         let result = match call_result {
             0 => ExecutionResult::Success {
                 reason: SuccessReason::Return,
                 gas_used: final_gas_used,
                 gas_refunded,
-                logs: vec![],
+                logs,
                 output,
             },
             -71 => ExecutionResult::Revert {
@@ -252,10 +260,7 @@ mod mainnet {
             },
         };
 
-        Ok(ResultAndState {
-            result,
-            state: State::new(),
-        })
+        Ok(ResultAndState { result, state })
     }
 
     /// Mainnet end handle does not change the output.
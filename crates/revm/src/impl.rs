@@ -1,9 +1,11 @@
 use crate::{
-    gas::Gas,
+    gas::{initial_tx_gas, Gas},
     handler::Handler,
+    inspector::{CallInputs, CallOutcome, CreateInputs, CreateOutcome, Inspector},
     types::{BytecodeType, CallCreateResult},
     EVMData,
 };
+use alloc::rc::Rc;
 use core::marker::PhantomData;
 use fluentbase_codec::Encoder;
 use fluentbase_core::{
@@ -24,25 +26,43 @@ use fluentbase_core_api::{
         WASM_CREATE_METHOD_ID,
     },
 };
-use fluentbase_sdk::{evm::ContractInput, LowLevelAPI, LowLevelSDK};
-use fluentbase_types::{Address, Bytes, ExitCode, STATE_DEPLOY, STATE_MAIN, U256};
+use fluentbase_sdk::{
+    call::CallScheme,
+    evm::{AccessListItem, ContractInput},
+    LowLevelAPI,
+    LowLevelSDK,
+};
+use fluentbase_types::{
+    Address,
+    Bytes,
+    ExitCode,
+    FuelSchedule,
+    B256,
+    CALL_STACK_LIMIT,
+    KECCAK_EMPTY,
+    POSEIDON_EMPTY,
+    STATE_MAIN,
+    U256,
+};
 use revm_primitives::{
+    Account as StateAccount,
+    AccountInfo,
     CreateScheme,
     EVMError,
     EVMResult,
     Env,
+    InvalidTransaction,
     Output,
     Spec,
     SpecId::*,
     TransactTo,
 };
 
-/// EVM call stack limit.
-pub const CALL_STACK_LIMIT: u64 = 1024;
-
 pub struct EVMImpl<'a, GSPEC: Spec> {
     pub data: EVMData<'a>,
     pub handler: Handler,
+    pub inspector: Option<&'a mut dyn Inspector>,
+    fuel_schedule: Rc<FuelSchedule>,
     depth: u64,
     _pd: PhantomData<GSPEC>,
 }
@@ -83,38 +103,80 @@ impl<'a, GSPEC: Spec + 'static> Transact<ExitCode> for EVMImpl<'a, GSPEC> {
 
 impl<'a, GSPEC: Spec + 'static> EVMImpl<'a, GSPEC> {
     pub fn new(env: &'a mut Env) -> Self {
+        Self::new_with_inspector(env, None)
+    }
+
+    pub fn new_with_inspector(env: &'a mut Env, inspector: Option<&'a mut dyn Inspector>) -> Self {
         Self {
-            data: EVMData { env },
+            data: EVMData {
+                env,
+                journal_checkpoint: 0,
+            },
             handler: Handler::mainnet::<GSPEC>(),
+            inspector,
+            fuel_schedule: Default::default(),
             _pd: PhantomData {},
             depth: 0,
         }
     }
 
+    /// Sets gas schedule of rWASM execution
+    pub fn with_fuel_schedule(mut self, fuel_schedule: Rc<FuelSchedule>) -> Self {
+        self.fuel_schedule = fuel_schedule;
+        self
+    }
+
     /// Pre verify transaction.
     pub fn preverify_transaction_inner(&mut self) -> Result<(), EVMError<ExitCode>> {
         // Important: validate block before tx.
         self.data.env.validate_block_env::<GSPEC>()?;
         self.data.env.validate_tx::<GSPEC>()?;
 
+        let env = &self.data.env;
+        let initial_gas_spend = initial_tx_gas::<GSPEC>(
+            &env.tx.data,
+            env.tx.transact_to.is_create(),
+            &env.tx.access_list,
+        );
+        // Additional check to see if limit is big enough to cover initial gas.
+        if initial_gas_spend > env.tx.gas_limit {
+            return Err(InvalidTransaction::CallGasCostMoreThanGasLimit.into());
+        }
+
         // load acc
-        // TODO: "warmup and verify tx caller?"
-        // let tx_caller = env.tx.caller;
-        // let (caller_account, _) = self
-        //     .data
-        //     .journaled_state
-        //     .load_account(tx_caller, self.data.db)
-        //     .map_err(EVMError::Database)?;
-        //
-        // self.data
-        //     .env
-        //     .validate_tx_against_state(caller_account)
-        //     .map_err(Into::into)
+        let mut caller_account = Account::new_from_jzkt(&env.tx.caller);
+        let has_code = caller_account.rwasm_bytecode_hash != POSEIDON_EMPTY
+            && caller_account.rwasm_bytecode_hash != B256::ZERO;
+        let mut state_account = StateAccount {
+            info: AccountInfo {
+                balance: caller_account.balance,
+                nonce: caller_account.nonce,
+                // we don't need real code hash here, it's used for EIP-3607 check only
+                code_hash: if has_code {
+                    caller_account.rwasm_bytecode_hash
+                } else {
+                    KECCAK_EMPTY
+                },
+                code: None,
+            },
+            ..Default::default()
+        };
+        self.data
+            .env
+            .validate_tx_against_state::<GSPEC>(&mut state_account)?;
+
+        // balance can be increased if balance check is disabled
+        if state_account.info.balance != caller_account.balance {
+            caller_account.balance = state_account.info.balance;
+            caller_account.write_to_jzkt();
+        }
         Ok(())
     }
 
     /// Transact preverified transaction.
     pub fn transact_preverified_inner(&mut self) -> EVMResult<ExitCode> {
+        self.data.journal_checkpoint = LowLevelSDK::jzkt_checkpoint();
+
         let env = &self.data.env;
         let tx_caller = env.tx.caller;
         let tx_value = env.tx.value;
@@ -143,13 +205,21 @@ impl<'a, GSPEC: Spec + 'static> EVMImpl<'a, GSPEC> {
             gas_cost = gas_cost.saturating_add(data_fee);
         }
         caller_account.sub_balance_saturating(gas_cost);
+        if let TransactTo::Call(_) = self.data.env.tx.transact_to {
+            caller_account.inc_nonce();
+        }
+        caller_account.write_to_jzkt();
 
-        let transact_gas_limit = tx_gas_limit;
+        let initial_gas_spend = initial_tx_gas::<GSPEC>(
+            &self.data.env.tx.data,
+            self.data.env.tx.transact_to.is_create(),
+            &self.data.env.tx.access_list,
+        );
+        let transact_gas_limit = tx_gas_limit.saturating_sub(initial_gas_spend);
 
         // call inner handling of call/create
         let (call_result, ret_gas, output) = match self.data.env.tx.transact_to {
             TransactTo::Call(address) => {
-                caller_account.inc_nonce();
                 let mut callee_account = Account::new_from_jzkt(&address);
                 let result = self.call_inner(
                     &mut caller_account,
@@ -157,6 +227,7 @@ impl<'a, GSPEC: Spec + 'static> EVMImpl<'a, GSPEC> {
                     tx_value,
                     tx_data,
                     transact_gas_limit,
+                    false,
                 );
                 (result.result, result.gas, Output::Call(result.return_value))
             }
@@ -189,11 +260,13 @@ impl<'a, GSPEC: Spec + 'static> EVMImpl<'a, GSPEC> {
         // set refund. Refund amount depends on hardfork.
         gas.set_refund(handler.calculate_gas_refund(data.env, &gas) as i64);
 
-        // Reimburse the caller
+        // Reimburse the caller, account is reloaded because it might be changed by the call
+        let mut caller_account = Account::new_from_jzkt(&tx_caller);
         let effective_gas_price = data.env.effective_gas_price();
         caller_account.add_balance_saturating(
             effective_gas_price * U256::from(gas.remaining() + gas.refunded() as u64),
         );
+        caller_account.write_to_jzkt();
 
         // Reward beneficiary
         if !data.env.cfg.is_beneficiary_reward_disabled() {
@@ -208,6 +281,7 @@ impl<'a, GSPEC: Spec + 'static> EVMImpl<'a, GSPEC> {
             coinbase_account.add_balance_saturating(
                 coinbase_gas_price * U256::from(gas.spend() - gas.refunded() as u64),
             );
+            coinbase_account.write_to_jzkt();
         }
 
         // main return
@@ -222,6 +296,42 @@ impl<'a, GSPEC: Spec + 'static> EVMImpl<'a, GSPEC> {
         input: Bytes,
         gas_limit: u64,
         salt: Option<U256>,
+    ) -> CallCreateResult {
+        let inputs = CreateInputs {
+            caller: caller_account.address,
+            value,
+            init_code: input.clone(),
+            gas_limit,
+            salt,
+        };
+        if let Some(inspector) = self.inspector.as_deref_mut() {
+            inspector.create(&mut self.data, &inputs);
+        }
+        let result = self.create_frame(caller_account, value, input, gas_limit, salt);
+        if let Some(inspector) = self.inspector.as_deref_mut() {
+            // geth reports runtime code of the deployed contract as an output
+            let output = result
+                .created_address
+                .map(|address| Account::new_from_jzkt(&address).load_source_bytecode())
+                .unwrap_or_default();
+            let outcome = CreateOutcome {
+                result: result.result,
+                address: result.created_address,
+                output,
+                gas_used: result.gas.spend(),
+            };
+            inspector.create_end(&mut self.data, &inputs, &outcome);
+        }
+        result
+    }
+
+    fn create_frame(
+        &mut self,
+        caller_account: &mut Account,
+        value: U256,
+        input: Bytes,
+        gas_limit: u64,
+        salt: Option<U256>,
     ) -> CallCreateResult {
         let mut gas = Gas::new(gas_limit);
         if self.depth > CALL_STACK_LIMIT {
@@ -300,7 +410,8 @@ impl<'a, GSPEC: Spec + 'static> EVMImpl<'a, GSPEC> {
             &mut middleware_account,
             core_input.into(),
             value,
-            STATE_DEPLOY,
+            false,
+            STATE_MAIN,
         );
 
         let created_address = if exit_code == ExitCode::Ok.into_i32() {
@@ -331,6 +442,51 @@ impl<'a, GSPEC: Spec + 'static> EVMImpl<'a, GSPEC> {
         value: U256,
         input: Bytes,
         gas_limit: u64,
+        is_static: bool,
+    ) -> CallCreateResult {
+        let inputs = CallInputs {
+            scheme: if is_static {
+                CallScheme::StaticCall
+            } else {
+                CallScheme::Call
+            },
+            caller: caller_account.address,
+            address: callee_account.address,
+            value,
+            input: input.clone(),
+            gas_limit,
+            is_static,
+        };
+        if let Some(inspector) = self.inspector.as_deref_mut() {
+            inspector.call(&mut self.data, &inputs);
+        }
+        let result = self.call_frame(
+            caller_account,
+            callee_account,
+            value,
+            input,
+            gas_limit,
+            is_static,
+        );
+        if let Some(inspector) = self.inspector.as_deref_mut() {
+            let outcome = CallOutcome {
+                result: result.result,
+                output: result.return_value.clone(),
+                gas_used: result.gas.spend(),
+            };
+            inspector.call_end(&mut self.data, &inputs, &outcome);
+        }
+        result
+    }
+
+    fn call_frame(
+        &mut self,
+        caller_account: &mut Account,
+        callee_account: &mut Account,
+        value: U256,
+        input: Bytes,
+        gas_limit: u64,
+        is_static: bool,
     ) -> CallCreateResult {
         let mut gas = Gas::new(gas_limit);
 
@@ -348,6 +504,7 @@ impl<'a, GSPEC: Spec + 'static> EVMImpl<'a, GSPEC> {
             callee_account,
             input,
             value,
+            is_static,
             STATE_MAIN,
         );
 
@@ -374,25 +531,52 @@ impl<'a, GSPEC: Spec + 'static> EVMImpl<'a, GSPEC> {
         callee: &mut Account,
         input: Bytes,
         value: U256,
+        is_static: bool,
     ) -> ContractInput {
         ContractInput {
             journal_checkpoint: checkpoint,
             env_chain_id: self.data.env.cfg.chain_id,
+            env_spec_id: Some(GSPEC::SPEC_ID as u8),
+            env_fuel_per_gas: self.fuel_schedule.fuel_per_gas(),
             contract_gas_limit: gas.remaining(),
             contract_address: callee.address,
             contract_caller: caller.address,
             contract_input: input,
             contract_value: value,
-            contract_is_static: false,
+            contract_is_static: is_static,
+            contract_depth: self.depth,
             block_coinbase: self.data.env.block.coinbase,
             block_timestamp: self.data.env.block.timestamp.as_limbs()[0],
             block_number: self.data.env.block.number.as_limbs()[0],
             block_difficulty: self.data.env.block.difficulty.as_limbs()[0],
             block_gas_limit: self.data.env.block.gas_limit.as_limbs()[0],
             block_base_fee: self.data.env.block.basefee,
+            block_prevrandao: self.data.env.block.prevrandao.unwrap_or_default(),
             tx_gas_price: self.data.env.tx.gas_price,
             tx_gas_priority_fee: self.data.env.tx.gas_priority_fee,
             tx_caller: self.data.env.tx.caller,
+            tx_gas_limit: self.data.env.tx.gas_limit,
+            tx_nonce: self.data.env.tx.nonce.unwrap_or_default(),
+            tx_access_list: self
+                .data
+                .env
+                .tx
+                .access_list
+                .iter()
+                .map(|(address, storage_keys)| AccessListItem {
+                    address: *address,
+                    storage_keys: storage_keys.clone(),
+                })
+                .collect(),
+            tx_blob_hashes: self.data.env.tx.blob_hashes.clone(),
+            tx_blob_gas_price: self
+                .data
+                .env
+                .block
+                .blob_excess_gas_and_price
+                .as_ref()
+                .map(|v| U256::from(v.blob_gasprice))
+                .unwrap_or_default(),
         }
     }
 
@@ -404,26 +588,49 @@ impl<'a, GSPEC: Spec + 'static> EVMImpl<'a, GSPEC> {
         callee: &mut Account,
         input: Bytes,
         value: U256,
+        is_static: bool,
         state: u32,
     ) -> (Bytes, i32) {
         let input = self
-            .input_from_env(checkpoint, gas, caller, callee, input, value)
+            .input_from_env(checkpoint, gas, caller, callee, input, value, is_static)
             .encode_to_vec(0);
 
-        let mut gas_limit_ref = gas.remaining() as u32;
-        let gas_limit_ref = &mut gas_limit_ref as *mut u32;
+        // nested executions inherit the schedule from the context
+        #[cfg(feature = "std")]
+        LowLevelSDK::with_fuel_schedule(self.fuel_schedule.clone());
+        #[cfg(feature = "std")]
+        let frames_offset = crate::state::journal_frames_len();
+
+        let fuel_limit = self.fuel_schedule.gas_to_fuel(gas.remaining());
+        let mut fuel = fuel_limit;
         let exit_code = LowLevelSDK::sys_exec_hash(
             callee.rwasm_bytecode_hash.as_ptr(),
             input.as_ptr(),
             input.len() as u32,
             core::ptr::null_mut(),
             0,
-            gas_limit_ref,
+            &mut fuel as *mut u64,
             state,
         );
-        let gas_used = gas.remaining() - unsafe { *gas_limit_ref } as u64;
+        // partially consumed gas is rounded up, so it can't exceed remaining gas
+        let gas_used = self
+            .fuel_schedule
+            .fuel_to_gas(fuel_limit - fuel)
+            .min(gas.remaining());
         gas.record_cost(gas_used);
 
+        // logs must be inspected before the frame is reverted
+        #[cfg(feature = "std")]
+        if let Some(inspector) = self.inspector.as_deref_mut() {
+            crate::state::inspect_journal(
+                inspector,
+                &mut self.data,
+                &self.fuel_schedule,
+                checkpoint,
+                frames_offset,
+            );
+        }
+
         let output_size = LowLevelSDK::sys_output_size();
         let mut output_buffer = vec![0u8; output_size as usize];
         LowLevelSDK::sys_read_output(output_buffer.as_mut_ptr(), 0, output_size);
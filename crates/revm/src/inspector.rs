@@ -0,0 +1,88 @@
+use crate::EVMData;
+use fluentbase_sdk::call::CallScheme;
+use fluentbase_types::{Address, Bytes, U256};
+use revm_primitives::Log;
+
+/// Inputs of the call frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallInputs {
+    pub scheme: CallScheme,
+    pub caller: Address,
+    /// Callee, for DELEGATECALL and CALLCODE it's an address of the executed code
+    pub address: Address,
+    pub value: U256,
+    pub input: Bytes,
+    pub gas_limit: u64,
+    pub is_static: bool,
+}
+
+/// Inputs of the create frame, salt is set for CREATE2 only
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateInputs {
+    pub caller: Address,
+    pub value: U256,
+    pub init_code: Bytes,
+    pub gas_limit: u64,
+    pub salt: Option<U256>,
+}
+
+/// Result of the call frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallOutcome {
+    /// Exit code of the callee
+    pub result: i32,
+    pub output: Bytes,
+    pub gas_used: u64,
+}
+
+/// Result of the create frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateOutcome {
+    /// Exit code of the create
+    pub result: i32,
+    /// Address of the deployed contract (only if create succeed)
+    pub address: Option<Address>,
+    /// Runtime code of the deployed contract, for failed creates it's the revert output
+    pub output: Bytes,
+    pub gas_used: u64,
+}
+
+/// Inspector hooks, they're similar to revm's `Inspector` and can be used to build tracers.
+///
+/// Hooks are invoked for the frames that are executed by `EVMImpl` itself (no matter whether
+/// the callee is an EVM contract executed via ECL or a WASM contract). Nested calls and creates
+/// are executed inside rWASM, so they're replayed from the journal's frame trace after the
+/// frame's execution together with logs, that's why they're available with `std` feature only.
+#[auto_impl::auto_impl(&mut, Box)]
+pub trait Inspector {
+    /// Called before the call frame is executed
+    fn call(&mut self, _data: &mut EVMData<'_>, _inputs: &CallInputs) {}
+
+    /// Called after the call frame is executed
+    fn call_end(&mut self, _data: &mut EVMData<'_>, _inputs: &CallInputs, _outcome: &CallOutcome) {}
+
+    /// Called before the create frame is executed
+    fn create(&mut self, _data: &mut EVMData<'_>, _inputs: &CreateInputs) {}
+
+    /// Called after the create frame is executed
+    fn create_end(
+        &mut self,
+        _data: &mut EVMData<'_>,
+        _inputs: &CreateInputs,
+        _outcome: &CreateOutcome,
+    ) {
+    }
+
+    /// Called for every log emitted by the frame
+    fn log(&mut self, _data: &mut EVMData<'_>, _log: &Log) {}
+
+    /// Called for every account removed by the frame, the value is the balance of the removed
+    /// account
+    fn selfdestruct(&mut self, _data: &mut EVMData<'_>, _contract: Address, _value: U256) {}
+}
+
+/// Inspector that does nothing
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoOpInspector;
+
+impl Inspector for NoOpInspector {}
@@ -8,17 +8,26 @@
 #[macro_use]
 extern crate alloc;
 
+#[cfg(feature = "std")]
+pub mod block;
 mod context;
 mod evm;
 pub mod handler;
 mod r#impl;
 
 mod gas;
+pub mod inspector;
+#[cfg(feature = "std")]
+mod state;
 #[cfg(test)]
 mod test;
+#[cfg(feature = "std")]
+pub mod tracers;
 mod types;
 
 pub use context::EVMData;
-pub use evm::{evm_inner, new, EVM};
+pub use evm::{evm_inner, inspect_inner, new, spec_id_from_chain_config, DEFAULT_SPEC_ID, EVM};
+pub use fluentbase_types::CALL_STACK_LIMIT;
 pub use handler::Handler;
-pub use r#impl::{EVMImpl, Transact, CALL_STACK_LIMIT};
+pub use inspector::Inspector;
+pub use r#impl::{EVMImpl, Transact};
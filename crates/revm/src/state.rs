@@ -0,0 +1,491 @@
+use crate::{
+    inspector::{CallInputs, CallOutcome, CreateInputs, CreateOutcome, Inspector},
+    EVMData,
+};
+use fluentbase_codec::{BufferDecoder, Encoder};
+use fluentbase_core::{
+    consts::{ECL_CONTRACT_ADDRESS, WCL_CONTRACT_ADDRESS},
+    Account,
+};
+use fluentbase_core_api::{
+    api::{CoreInput, ICoreInput},
+    bindings::{
+        EvmCallcodeMethodInput,
+        EvmCreate2MethodInput,
+        EvmCreateMethodInput,
+        EvmDelegatecallMethodInput,
+        IEvmCallcodeMethodInput,
+        IEvmCreate2MethodInput,
+        IEvmCreateMethodInput,
+        IEvmDelegatecallMethodInput,
+        IWasmCreate2MethodInput,
+        IWasmCreateMethodInput,
+        WasmCreate2MethodInput,
+        WasmCreateMethodInput,
+        EVM_CALLCODE_METHOD_ID,
+        EVM_CREATE2_METHOD_ID,
+        EVM_CREATE_METHOD_ID,
+        EVM_DELEGATECALL_METHOD_ID,
+        WASM_CREATE2_METHOD_ID,
+        WASM_CREATE_METHOD_ID,
+    },
+};
+use fluentbase_runtime::{JournalChange, JournalCheckpoint, JournalFrame, JournalLog};
+use fluentbase_sdk::{
+    call::CallScheme,
+    evm::{ContractInput, IContractInput},
+    LowLevelSDK,
+};
+use fluentbase_types::{
+    Address,
+    Bytes,
+    ExitCode,
+    FuelSchedule,
+    B256,
+    JZKT_ACCOUNT_FIELDS_COUNT,
+    KECCAK_EMPTY,
+    STATE_DEPLOY,
+    STATE_STATIC_FLAG,
+};
+use revm_primitives::{
+    Account as StateAccount,
+    AccountInfo,
+    Bytecode,
+    Log,
+    State,
+    StorageSlot,
+    U256,
+};
+
+/// Converts changes of the journal made after the checkpoint into revm's state diff and logs.
+///
+/// Storage slots are tracked only for the items that were written or read using
+/// `_jzkt_store`/`_jzkt_load`, other non-account keys are ignored.
+pub(crate) fn journal_state_and_logs(checkpoint: u64) -> (State, Vec<Log>) {
+    let Some(jzkt) = LowLevelSDK::get_jzkt() else {
+        return (State::new(), vec![]);
+    };
+    // collect everything first, because loading of account's code borrows jzkt as well
+    let (changes, logs) = {
+        let jzkt = jzkt.borrow();
+        let checkpoint = JournalCheckpoint::from_u64(checkpoint);
+        (jzkt.changes(checkpoint), jzkt.logs(checkpoint).to_vec())
+    };
+    let mut state = State::new();
+    for change in changes {
+        if let Some((address, slot)) = change.storage_slot {
+            apply_storage_change(&mut state, address, slot, &change);
+        } else if let Some(address) = account_address(&change) {
+            apply_account_change(&mut state, address, &change);
+        }
+    }
+    let logs = logs.into_iter().map(map_log).collect();
+    (state, logs)
+}
+
+/// Passes nested frames and logs made after the checkpoint to the inspector.
+///
+/// Nested frames are replayed from the journal's frame trace starting from `frames_offset`, the
+/// first traced frame is the one that is executed by `EVMImpl` and it's already reported.
+pub(crate) fn inspect_journal(
+    inspector: &mut dyn Inspector,
+    data: &mut EVMData<'_>,
+    fuel_schedule: &FuelSchedule,
+    checkpoint: u64,
+    frames_offset: usize,
+) {
+    let Some(jzkt) = LowLevelSDK::get_jzkt() else {
+        return;
+    };
+    let (frames, logs, changes) = {
+        let jzkt = jzkt.borrow();
+        let checkpoint = JournalCheckpoint::from_u64(checkpoint);
+        (
+            jzkt.frames()
+                .get(frames_offset..)
+                .unwrap_or_default()
+                .to_vec(),
+            jzkt.logs(checkpoint).to_vec(),
+            jzkt.changes(checkpoint),
+        )
+    };
+    inspect_frames(inspector, data, fuel_schedule, frames);
+    for log in logs {
+        inspector.log(data, &map_log(log));
+    }
+    // removed accounts are reported as selfdestructed with the balance they had
+    for change in changes {
+        if change.storage_slot.is_some() || change.value.is_some() {
+            continue;
+        }
+        let (Some(address), Some(prev_value)) = (account_address(&change), &change.prev_value)
+        else {
+            continue;
+        };
+        let prev_account = Account::new_from_fields(&address, prev_value);
+        inspector.selfdestruct(data, address, prev_account.balance);
+    }
+}
+
+/// Returns the number of traced frames, it's used as an offset for [`inspect_journal`]
+pub(crate) fn journal_frames_len() -> usize {
+    LowLevelSDK::get_jzkt()
+        .map(|jzkt| jzkt.borrow().frames().len())
+        .unwrap_or_default()
+}
+
+enum InspectedFrame {
+    Call(CallInputs),
+    Create(CreateInputs),
+}
+
+fn inspect_frames(
+    inspector: &mut dyn Inspector,
+    data: &mut EVMData<'_>,
+    fuel_schedule: &FuelSchedule,
+    frames: Vec<JournalFrame>,
+) {
+    // calls and creates of EVM contracts are executed by ECL and WCL, so their frames are decoded
+    // from the core input
+    let system_hashes = [ECL_CONTRACT_ADDRESS, WCL_CONTRACT_ADDRESS]
+        .map(|address| Account::new_from_jzkt(&address).rwasm_bytecode_hash.0);
+    // `None` is pushed for the frames that aren't reported (f.e. constructors or EVM loaders), but
+    // their children are still reported
+    let mut stack: Vec<Option<InspectedFrame>> = vec![];
+    for frame in frames {
+        match frame {
+            JournalFrame::Enter {
+                bytecode_hash,
+                input,
+                state,
+            } => {
+                let is_system = bytecode_hash
+                    .map(|hash| system_hashes.contains(&hash))
+                    .unwrap_or(false);
+                let frame = if stack.is_empty() {
+                    None
+                } else {
+                    inspected_frame(&input, state, bytecode_hash, is_system)
+                };
+                match &frame {
+                    Some(InspectedFrame::Call(inputs)) => inspector.call(data, inputs),
+                    Some(InspectedFrame::Create(inputs)) => inspector.create(data, inputs),
+                    None => {}
+                }
+                stack.push(frame);
+            }
+            JournalFrame::Exit {
+                exit_code,
+                output,
+                fuel_consumed,
+            } => {
+                let Some(frame) = stack.pop() else {
+                    continue;
+                };
+                let gas_used = fuel_schedule.fuel_to_gas(fuel_consumed);
+                match frame {
+                    Some(InspectedFrame::Call(inputs)) => {
+                        let outcome = CallOutcome {
+                            result: exit_code,
+                            output: output.into(),
+                            gas_used,
+                        };
+                        inspector.call_end(data, &inputs, &outcome);
+                    }
+                    Some(InspectedFrame::Create(inputs)) => {
+                        let address = match output.get(..20) {
+                            Some(address) if exit_code == ExitCode::Ok.into_i32() => {
+                                Some(Address::from_slice(address))
+                            }
+                            _ => None,
+                        };
+                        // geth reports runtime code of the deployed contract as an output
+                        let output = match address {
+                            Some(address) => {
+                                Account::new_from_jzkt(&address).load_source_bytecode()
+                            }
+                            None => output.into(),
+                        };
+                        let outcome = CreateOutcome {
+                            result: exit_code,
+                            address,
+                            output,
+                            gas_used,
+                        };
+                        inspector.create_end(data, &inputs, &outcome);
+                    }
+                    None => {}
+                }
+            }
+        }
+    }
+}
+
+/// Decodes inputs of the nested frame, frames' inputs are provided by contracts, so malformed
+/// inputs aren't reported instead of panicking
+fn inspected_frame(
+    input: &[u8],
+    state: u32,
+    bytecode_hash: Option<[u8; 32]>,
+    is_system: bool,
+) -> Option<InspectedFrame> {
+    let caller: Address = read_field(
+        input,
+        <ContractInput as IContractInput>::ContractCaller::FIELD_OFFSET,
+    )?;
+    let address: Address = read_field(
+        input,
+        <ContractInput as IContractInput>::ContractAddress::FIELD_OFFSET,
+    )?;
+    let value: U256 = read_field(
+        input,
+        <ContractInput as IContractInput>::ContractValue::FIELD_OFFSET,
+    )?;
+    let gas_limit: u64 = read_field(
+        input,
+        <ContractInput as IContractInput>::ContractGasLimit::FIELD_OFFSET,
+    )?;
+    let is_static: bool = read_field(
+        input,
+        <ContractInput as IContractInput>::ContractIsStatic::FIELD_OFFSET,
+    )?;
+    let contract_input = read_bytes_field(
+        input,
+        <ContractInput as IContractInput>::ContractInput::FIELD_OFFSET,
+    )?;
+    if !is_system {
+        // deploy frames of WASM contracts are constructors, they're reported by the create frame
+        if state & !STATE_STATIC_FLAG == STATE_DEPLOY {
+            return None;
+        }
+        // WASM callees of DELEGATECALL and CALLCODE are executed directly within the caller's
+        // context, so they're recognized by the code that isn't the context's one
+        let is_foreign_code = bytecode_hash
+            .is_some_and(|hash| hash != Account::new_from_jzkt(&address).rwasm_bytecode_hash.0);
+        let scheme = if is_static {
+            CallScheme::StaticCall
+        } else if is_foreign_code && caller == address {
+            CallScheme::CallCode
+        } else if is_foreign_code {
+            CallScheme::DelegateCall
+        } else {
+            CallScheme::Call
+        };
+        return Some(InspectedFrame::Call(CallInputs {
+            scheme,
+            caller,
+            address,
+            value,
+            input: Bytes::copy_from_slice(contract_input),
+            gas_limit,
+            is_static,
+        }));
+    }
+    let method_id: u32 = read_field(
+        contract_input,
+        <CoreInput as ICoreInput>::MethodId::FIELD_OFFSET,
+    )?;
+    let method_data = read_vec_field(
+        contract_input,
+        <CoreInput as ICoreInput>::MethodData::FIELD_OFFSET,
+    )?;
+    let create = |code_offset: usize, salt_offset: Option<usize>| {
+        let init_code = read_vec_field(method_data, code_offset)?;
+        let salt = match salt_offset {
+            Some(salt_offset) => Some(U256::from_be_bytes(read_field::<[u8; 32]>(
+                method_data,
+                salt_offset,
+            )?)),
+            None => None,
+        };
+        Some(InspectedFrame::Create(CreateInputs {
+            caller,
+            value,
+            init_code: Bytes::copy_from_slice(init_code),
+            gas_limit,
+            salt,
+        }))
+    };
+    match method_id {
+        EVM_CREATE_METHOD_ID => create(
+            <EvmCreateMethodInput as IEvmCreateMethodInput>::Code::FIELD_OFFSET,
+            None,
+        ),
+        EVM_CREATE2_METHOD_ID => create(
+            <EvmCreate2MethodInput as IEvmCreate2MethodInput>::Code::FIELD_OFFSET,
+            Some(<EvmCreate2MethodInput as IEvmCreate2MethodInput>::Salt32::FIELD_OFFSET),
+        ),
+        WASM_CREATE_METHOD_ID => create(
+            <WasmCreateMethodInput as IWasmCreateMethodInput>::Code::FIELD_OFFSET,
+            None,
+        ),
+        WASM_CREATE2_METHOD_ID => create(
+            <WasmCreate2MethodInput as IWasmCreate2MethodInput>::Code::FIELD_OFFSET,
+            Some(<WasmCreate2MethodInput as IWasmCreate2MethodInput>::Salt32::FIELD_OFFSET),
+        ),
+        // ECL executes the callee's bytecode in the context of the caller, so the caller is the
+        // context's address
+        EVM_DELEGATECALL_METHOD_ID => {
+            let callee: [u8; 20] =
+                read_field(method_data, <EvmDelegatecallMethodInput as IEvmDelegatecallMethodInput>::CalleeAddress20::FIELD_OFFSET)?;
+            let args = read_vec_field(
+                method_data,
+                <EvmDelegatecallMethodInput as IEvmDelegatecallMethodInput>::Args::FIELD_OFFSET,
+            )?;
+            Some(InspectedFrame::Call(CallInputs {
+                scheme: CallScheme::DelegateCall,
+                caller: address,
+                address: Address::from(callee),
+                value,
+                input: Bytes::copy_from_slice(args),
+                gas_limit,
+                is_static,
+            }))
+        }
+        EVM_CALLCODE_METHOD_ID => {
+            let callee: [u8; 20] = read_field(
+                method_data,
+                <EvmCallcodeMethodInput as IEvmCallcodeMethodInput>::CalleeAddress20::FIELD_OFFSET,
+            )?;
+            let value32: [u8; 32] = read_field(
+                method_data,
+                <EvmCallcodeMethodInput as IEvmCallcodeMethodInput>::Value32::FIELD_OFFSET,
+            )?;
+            let args = read_vec_field(
+                method_data,
+                <EvmCallcodeMethodInput as IEvmCallcodeMethodInput>::Args::FIELD_OFFSET,
+            )?;
+            Some(InspectedFrame::Call(CallInputs {
+                scheme: CallScheme::CallCode,
+                caller: address,
+                address: Address::from(callee),
+                value: U256::from_be_bytes(value32),
+                input: Bytes::copy_from_slice(args),
+                gas_limit,
+                is_static,
+            }))
+        }
+        // the rest of the methods are executed by EVM loaders, that are already reported
+        _ => None,
+    }
+}
+
+/// Reads the fixed size field of the encoded struct, `None` if it's out of the buffer's bounds
+fn read_field<T: Default + Encoder<T>>(buffer: &[u8], field_offset: usize) -> Option<T> {
+    if buffer.len() < field_offset.checked_add(T::HEADER_SIZE)? {
+        return None;
+    }
+    let mut result = T::default();
+    T::decode_header(&mut BufferDecoder::new(buffer), field_offset, &mut result);
+    Some(result)
+}
+
+/// Reads the `Bytes` field of the encoded struct, `None` if it's out of the buffer's bounds
+fn read_bytes_field(buffer: &[u8], field_offset: usize) -> Option<&[u8]> {
+    let offset: u32 = read_field(buffer, field_offset)?;
+    let length: u32 = read_field(buffer, field_offset + 4)?;
+    buffer.get(offset as usize..(offset as usize).checked_add(length as usize)?)
+}
+
+/// Reads the `Vec<u8>` field of the encoded struct, the data goes after the elements count
+fn read_vec_field(buffer: &[u8], field_offset: usize) -> Option<&[u8]> {
+    read_bytes_field(buffer, field_offset + 4)
+}
+
+fn apply_storage_change(
+    state: &mut State,
+    address: Address,
+    slot: [u8; 32],
+    change: &JournalChange,
+) {
+    let storage_word = |value: &Option<Vec<[u8; 32]>>| {
+        value
+            .as_ref()
+            .and_then(|v| v.first())
+            .map(|v| U256::from_le_bytes(*v))
+            .unwrap_or_default()
+    };
+    let account = state.entry(address).or_insert_with(|| StateAccount {
+        info: account_info(&Account::new_from_jzkt(&address), false),
+        ..Default::default()
+    });
+    account.mark_touch();
+    account.storage.insert(
+        U256::from_le_bytes(slot),
+        StorageSlot::new_changed(
+            storage_word(&change.prev_value),
+            storage_word(&change.value),
+        ),
+    );
+}
+
+fn apply_account_change(state: &mut State, address: Address, change: &JournalChange) {
+    let prev_account = change
+        .prev_value
+        .as_ref()
+        .map(|fields| Account::new_from_fields(&address, fields));
+    let account = state.entry(address).or_default();
+    account.mark_touch();
+    match &change.value {
+        Some(fields) => {
+            let new_account = Account::new_from_fields(&address, fields);
+            // load code only if it's changed
+            let code_changed = prev_account
+                .map(|prev_account| {
+                    prev_account.source_bytecode_hash != new_account.source_bytecode_hash
+                })
+                .unwrap_or(true);
+            account.info = account_info(&new_account, code_changed);
+            if change.prev_value.is_none() {
+                account.mark_created();
+            }
+        }
+        None => {
+            if let Some(prev_account) = prev_account {
+                account.info = account_info(&prev_account, false);
+            }
+            account.mark_selfdestruct();
+        }
+    }
+}
+
+fn account_info(account: &Account, with_code: bool) -> AccountInfo {
+    let has_code = account.source_bytecode_size > 0;
+    let code_hash = if has_code && account.source_bytecode_hash != B256::ZERO {
+        account.source_bytecode_hash
+    } else {
+        KECCAK_EMPTY
+    };
+    let code = if with_code && has_code {
+        Some(Bytecode::new_raw(account.load_source_bytecode()))
+    } else {
+        None
+    };
+    AccountInfo {
+        balance: account.balance,
+        nonce: account.nonce,
+        code_hash,
+        code,
+    }
+}
+
+/// Account keys are addresses padded to 32 bytes, storage keys are poseidon hashes
+fn account_address(change: &JournalChange) -> Option<Address> {
+    let is_account_fields = |value: &Option<Vec<[u8; 32]>>| {
+        value
+            .as_ref()
+            .map(|v| v.len() == JZKT_ACCOUNT_FIELDS_COUNT as usize)
+            .unwrap_or(true)
+    };
+    if change.key[0..12] != [0u8; 12]
+        || !is_account_fields(&change.prev_value)
+        || !is_account_fields(&change.value)
+    {
+        return None;
+    }
+    Some(Address::from_slice(&change.key[12..]))
+}
+
+fn map_log(log: JournalLog) -> Log {
+    Log::new_unchecked(log.address, log.topics, log.data)
+}
@@ -1,12 +1,34 @@
-use crate::EVM;
-use fluentbase_core::{helpers::calc_create_address, Account};
-use fluentbase_genesis::{devnet::devnet_genesis, Genesis, EXAMPLE_GREETING_ADDRESS};
+use crate::{
+    block::{BlockExecutor, BlockTransaction},
+    spec_id_from_chain_config,
+    tracers::{CallTracer, FourByteTracer},
+    EVM,
+};
+use fluentbase_codec::{BufferDecoder, Encoder};
+use fluentbase_core::{helpers::calc_create_address, wasm::gas::code_deposit_cost, Account};
+use fluentbase_genesis::{devnet::devnet_genesis, ChainConfig, Genesis, EXAMPLE_GREETING_ADDRESS};
 use fluentbase_runtime::IJournaledTrie;
-use fluentbase_sdk::LowLevelSDK;
-use fluentbase_types::{Address, Bytes};
-use revm_primitives::{hex, CreateScheme, Env, TransactTo};
+use fluentbase_sdk::{
+    call::call,
+    evm::{ContractInput, ExecutionContext, WasmDeployInput},
+    LowLevelSDK,
+};
+use fluentbase_types::{address, Address, Bytes, FuelSchedule, B256, POSEIDON_EMPTY, U256};
+use revm_primitives::{
+    hex,
+    BlockEnv,
+    CfgEnv,
+    CreateScheme,
+    Env,
+    InvalidTransaction,
+    SpecId,
+    TransactTo,
+    TxEnv,
+};
 use std::{cell::RefCell, rc::Rc};
 
+const EVM_GREETING_BYTECODE: &[u8] = &hex!("60806040526105ae806100115f395ff3fe608060405234801561000f575f80fd5b506004361061003f575f3560e01c80633b2e97481461004357806345773e4e1461007357806348b8bcc314610091575b5f80fd5b61005d600480360381019061005891906102e5565b6100af565b60405161006a919061039a565b60405180910390f35b61007b6100dd565b604051610088919061039a565b60405180910390f35b61009961011a565b6040516100a6919061039a565b60405180910390f35b60605f8273ffffffffffffffffffffffffffffffffffffffff163190506100d58161012f565b915050919050565b60606040518060400160405280600b81526020017f48656c6c6f20576f726c64000000000000000000000000000000000000000000815250905090565b60605f4790506101298161012f565b91505090565b60605f8203610175576040518060400160405280600181526020017f30000000000000000000000000000000000000000000000000000000000000008152509050610282565b5f8290505f5b5f82146101a457808061018d906103f0565b915050600a8261019d9190610464565b915061017b565b5f8167ffffffffffffffff8111156101bf576101be610494565b5b6040519080825280601f01601f1916602001820160405280156101f15781602001600182028036833780820191505090505b5090505b5f851461027b578180610207906104c1565b925050600a8561021791906104e8565b60306102239190610518565b60f81b8183815181106102395761023861054b565b5b60200101907effffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff191690815f1a905350600a856102749190610464565b94506101f5565b8093505050505b919050565b5f80fd5b5f73ffffffffffffffffffffffffffffffffffffffff82169050919050565b5f6102b48261028b565b9050919050565b6102c4816102aa565b81146102ce575f80fd5b50565b5f813590506102df816102bb565b92915050565b5f602082840312156102fa576102f9610287565b5b5f610307848285016102d1565b91505092915050565b5f81519050919050565b5f82825260208201905092915050565b5f5b8381101561034757808201518184015260208101905061032c565b5f8484015250505050565b5f601f19601f8301169050919050565b5f61036c82610310565b610376818561031a565b935061038681856020860161032a565b61038f81610352565b840191505092915050565b5f6020820190508181035f8301526103b28184610362565b905092915050565b7f4e487b71000000000000000000000000000000000000000000000000000000005f52601160045260245ffd5b5f819050919050565b5f6103fa826103e7565b91507fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff820361042c5761042b6103ba565b5b600182019050919050565b7f4e487b71000000000000000000000000000000000000000000000000000000005f52601260045260245ffd5b5f61046e826103e7565b9150610479836103e7565b92508261048957610488610437565b5b828204905092915050565b7f4e487b71000000000000000000000000000000000000000000000000000000005f52604160045260245ffd5b5f6104cb826103e7565b91505f82036104dd576104dc6103ba565b5b600182039050919050565b5f6104f2826103e7565b91506104fd836103e7565b92508261050d5761050c610437565b5b828206905092915050565b5f610522826103e7565b915061052d836103e7565b9250828201905080821115610545576105446103ba565b5b92915050565b7f4e487b71000000000000000000000000000000000000000000000000000000005f52603260045260245ffdfea2646970667358221220feebf5ace29c3c3146cb63bf7ca9009c2005f349075639d267cfbd817adde3e564736f6c63430008180033");
+
 struct TestingContext {
     genesis: Genesis,
     jzkt: Rc<RefCell<dyn IJournaledTrie>>,
@@ -57,6 +79,24 @@ fn test_genesis_greeting() {
     );
 }
 
+#[test]
+fn test_fuel_schedule() {
+    let _ctx = TestingContext::default();
+    let mut env = Env::default();
+    env.tx.transact_to = TransactTo::Call(EXAMPLE_GREETING_ADDRESS);
+    env.tx.gas_limit = 3_000_000;
+    let gas_used = |fuel_schedule: FuelSchedule| {
+        let mut evm = EVM::with_env(env.clone()).with_fuel_schedule(fuel_schedule);
+        let result = evm.transact().unwrap();
+        assert!(result.result.is_success());
+        result.result.gas_used()
+    };
+    let default_gas_used = gas_used(FuelSchedule::default());
+    // the same execution is cheaper if more instructions are equal to one unit of gas
+    let cheap_gas_used = gas_used(FuelSchedule::default().with_fuel_per_gas(10));
+    assert!(cheap_gas_used < default_gas_used);
+}
+
 #[test]
 fn test_deploy_greeting() {
     // deploy greeting WASM contract
@@ -87,6 +127,80 @@ fn test_deploy_greeting() {
     );
 }
 
+#[test]
+fn test_deploy_wasm_with_args() {
+    // deploy function returns its whole input, so it's stored as immutable data
+    let wasm = wat::parse_str(
+        r#"
+(module
+  (type (;0;) (func (result i32)))
+  (type (;1;) (func (param i32 i32 i32)))
+  (type (;2;) (func (param i32 i32)))
+  (type (;3;) (func))
+  (import "fluentbase_v1alpha" "_sys_input_size" (func $_sys_input_size (type 0)))
+  (import "fluentbase_v1alpha" "_sys_read" (func $_sys_read (type 1)))
+  (import "fluentbase_v1alpha" "_sys_write" (func $_sys_write (type 2)))
+  (func $deploy (type 3)
+    (local i32)
+    call $_sys_input_size
+    local.set 0
+    i32.const 0
+    i32.const 0
+    local.get 0
+    call $_sys_read
+    i32.const 0
+    local.get 0
+    call $_sys_write
+    )
+  (func $main (type 3))
+  (memory (;0;) 1)
+  (export "memory" (memory 0))
+  (export "deploy" (func $deploy))
+  (export "main" (func $main)))
+    "#,
+    )
+    .unwrap();
+    let _ctx = TestingContext::default();
+    const DEPLOYER_ADDRESS: Address = address!("1231238908230948230948209348203984029834");
+    let args = Bytes::from_static(b"constructor args");
+    let mut env = Env::default();
+    env.cfg.chain_id = 1337;
+    env.tx.caller = DEPLOYER_ADDRESS;
+    env.tx.transact_to = TransactTo::Create(CreateScheme::Create);
+    env.tx.data = WasmDeployInput {
+        wasm: wasm.into(),
+        args: args.clone(),
+    }
+    .encode_deploy_code()
+    .into();
+    env.tx.gas_limit = 3_000_000;
+    let mut evm = EVM::with_env(env);
+    let result = evm.transact().unwrap();
+    assert!(result.result.is_success());
+    let contract_address = calc_create_address(&DEPLOYER_ADDRESS, 0);
+    // read immutable data of the deployed contract
+    LowLevelSDK::with_test_input(
+        ContractInput {
+            contract_address,
+            ..Default::default()
+        }
+        .encode_to_vec(0),
+    );
+    let immutable_data = ExecutionContext::contract_immutable_data();
+    let mut deploy_input = ContractInput::default();
+    ContractInput::decode_body(
+        &mut BufferDecoder::new(immutable_data.as_ref()),
+        0,
+        &mut deploy_input,
+    );
+    // args are passed to the deploy function, the rest of the context is inherited
+    assert_eq!(deploy_input.contract_input, args);
+    assert_eq!(deploy_input.contract_address, contract_address);
+    assert_eq!(deploy_input.contract_caller, DEPLOYER_ADDRESS);
+    assert_eq!(deploy_input.env_chain_id, 1337);
+    assert_eq!(deploy_input.tx_caller, DEPLOYER_ADDRESS);
+}
+
 #[test]
 fn test_evm_greeting() {
     // deploy greeting EVM contract
@@ -95,7 +209,7 @@ fn test_evm_greeting() {
     const DEPLOYER_ADDRESS: Address = Address::ZERO;
     env.tx.caller = DEPLOYER_ADDRESS;
     env.tx.transact_to = TransactTo::Create(CreateScheme::Create);
-    env.tx.data = Bytes::from_static(&hex!("60806040526105ae806100115f395ff3fe608060405234801561000f575f80fd5b506004361061003f575f3560e01c80633b2e97481461004357806345773e4e1461007357806348b8bcc314610091575b5f80fd5b61005d600480360381019061005891906102e5565b6100af565b60405161006a919061039a565b60405180910390f35b61007b6100dd565b604051610088919061039a565b60405180910390f35b61009961011a565b6040516100a6919061039a565b60405180910390f35b60605f8273ffffffffffffffffffffffffffffffffffffffff163190506100d58161012f565b915050919050565b60606040518060400160405280600b81526020017f48656c6c6f20576f726c64000000000000000000000000000000000000000000815250905090565b60605f4790506101298161012f565b91505090565b60605f8203610175576040518060400160405280600181526020017f30000000000000000000000000000000000000000000000000000000000000008152509050610282565b5f8290505f5b5f82146101a457808061018d906103f0565b915050600a8261019d9190610464565b915061017b565b5f8167ffffffffffffffff8111156101bf576101be610494565b5b6040519080825280601f01601f1916602001820160405280156101f15781602001600182028036833780820191505090505b5090505b5f851461027b578180610207906104c1565b925050600a8561021791906104e8565b60306102239190610518565b60f81b8183815181106102395761023861054b565b5b60200101907effffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff191690815f1a905350600a856102749190610464565b94506101f5565b8093505050505b919050565b5f80fd5b5f73ffffffffffffffffffffffffffffffffffffffff82169050919050565b5f6102b48261028b565b9050919050565b6102c4816102aa565b81146102ce575f80fd5b50565b5f813590506102df816102bb565b92915050565b5f602082840312156102fa576102f9610287565b5b5f610307848285016102d1565b91505092915050565b5f81519050919050565b5f82825260208201905092915050565b5f5b8381101561034757808201518184015260208101905061032c565b5f8484015250505050565b5f601f19601f8301169050919050565b5f61036c82610310565b610376818561031a565b935061038681856020860161032a565b61038f81610352565b840191505092915050565b5f6020820190508181035f8301526103b28184610362565b905092915050565b7f4e487b71000000000000000000000000000000000000000000000000000000005f52601160045260245ffd5b5f819050919050565b5f6103fa826103e7565b91507fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff820361042c5761042b6103ba565b5b600182019050919050565b7f4e487b71000000000000000000000000000000000000000000000000000000005f52601260045260245ffd5b5f61046e826103e7565b9150610479836103e7565b92508261048957610488610437565b5b828204905092915050565b7f4e487b71000000000000000000000000000000000000000000000000000000005f52604160045260245ffd5b5f6104cb826103e7565b91505f82036104dd576104dc6103ba565b5b600182039050919050565b5f6104f2826103e7565b91506104fd836103e7565b92508261050d5761050c610437565b5b828206905092915050565b5f610522826103e7565b915061052d836103e7565b9250828201905080821115610545576105446103ba565b5b92915050565b7f4e487b71000000000000000000000000000000000000000000000000000000005f52603260045260245ffdfea2646970667358221220feebf5ace29c3c3146cb63bf7ca9009c2005f349075639d267cfbd817adde3e564736f6c63430008180033"));
+    env.tx.data = Bytes::from_static(EVM_GREETING_BYTECODE);
     env.tx.gas_limit = 3_000_000;
     let mut evm = EVM::with_env(env);
     let result = evm.transact().unwrap();
@@ -115,3 +229,430 @@ fn test_evm_greeting() {
     let bytes = &bytes[64..75];
     assert_eq!("Hello World", core::str::from_utf8(bytes.as_ref()).unwrap());
 }
+
+#[test]
+fn test_evm_call_wasm_contract() {
+    let _ctx = TestingContext::default();
+    let mut env = Env::default();
+    const DEPLOYER_ADDRESS: Address = Address::ZERO;
+    env.tx.caller = DEPLOYER_ADDRESS;
+    env.tx.transact_to = TransactTo::Create(CreateScheme::Create);
+    // call(gas, greeting, 0, 0, 0, 0, 0); returndatacopy(0, 0, returndatasize);
+    // return(0, returndatasize)
+    let runtime_code = format!(
+        "5f5f5f5f5f73{}5af1503d5f5f3e3d5ff3",
+        hex::encode(EXAMPLE_GREETING_ADDRESS)
+    );
+    // codecopy(0, 9, 0x24); return(0, 0x24)
+    env.tx.data = hex::decode(format!("60248060095f395ff3{runtime_code}"))
+        .unwrap()
+        .into();
+    env.tx.gas_limit = 3_000_000;
+    let mut evm = EVM::with_env(env);
+    let result = evm.transact().unwrap();
+    assert!(result.result.is_success());
+    let contract_address = calc_create_address(&DEPLOYER_ADDRESS, 0);
+    // EVM contract forwards output of the WASM contract
+    let mut env = Env::default();
+    env.tx.transact_to = TransactTo::Call(contract_address);
+    env.tx.gas_limit = 10_000_000;
+    let mut evm = EVM::with_env(env);
+    let result = evm.transact().unwrap();
+    assert!(result.result.is_success());
+    let bytes = result.result.output().unwrap_or_default();
+    assert_eq!(
+        "Hello, World",
+        core::str::from_utf8(bytes.as_ref()).unwrap()
+    );
+}
+
+#[test]
+fn test_wasm_call_evm_contract() {
+    let _ctx = TestingContext::default();
+    let mut env = Env::default();
+    const DEPLOYER_ADDRESS: Address = Address::ZERO;
+    env.tx.caller = DEPLOYER_ADDRESS;
+    env.tx.transact_to = TransactTo::Create(CreateScheme::Create);
+    env.tx.data = Bytes::from_static(EVM_GREETING_BYTECODE);
+    env.tx.gas_limit = 3_000_000;
+    let mut evm = EVM::with_env(env);
+    let result = evm.transact().unwrap();
+    assert!(result.result.is_success());
+    let contract_address = calc_create_address(&DEPLOYER_ADDRESS, 0);
+    // WASM contracts call other contracts through SDK, the same way as it's done here
+    let contract_input = ContractInput {
+        contract_address: EXAMPLE_GREETING_ADDRESS,
+        ..Default::default()
+    };
+    LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+    let output = call(contract_address, U256::ZERO, &hex!("45773e4e"), 10_000_000).unwrap();
+    let bytes = &output[64..75];
+    assert_eq!("Hello World", core::str::from_utf8(bytes).unwrap());
+}
+
+#[test]
+fn test_evm_nested_create() {
+    let _ctx = TestingContext::default();
+    let mut env = Env::default();
+    const DEPLOYER_ADDRESS: Address = Address::ZERO;
+    env.tx.caller = DEPLOYER_ADDRESS;
+    env.tx.transact_to = TransactTo::Create(CreateScheme::Create);
+    // mstore(0, 0x60006000f3); mstore(0, create(0, 27, 5)); return(0, 32)
+    let runtime_code = "6460006000f35f526005601b5ff05f5260205ff3";
+    // codecopy(0, 9, 0x14); return(0, 0x14)
+    env.tx.data = hex::decode(format!("60148060095f395ff3{runtime_code}"))
+        .unwrap()
+        .into();
+    env.tx.gas_limit = 3_000_000;
+    let mut evm = EVM::with_env(env);
+    let result = evm.transact().unwrap();
+    assert!(result.result.is_success());
+    let factory_address = calc_create_address(&DEPLOYER_ADDRESS, 0);
+    // factory deploys new contract and returns its address
+    let mut env = Env::default();
+    env.tx.transact_to = TransactTo::Call(factory_address);
+    env.tx.gas_limit = 10_000_000;
+    let mut evm = EVM::with_env(env);
+    let result = evm.transact().unwrap();
+    assert!(result.result.is_success());
+    let output = result.result.output().unwrap_or_default();
+    assert_eq!(output.len(), 32);
+    let created_address = Address::from_slice(&output[12..]);
+    assert_ne!(created_address, Address::ZERO);
+    let account = Account::new_from_jzkt(&created_address);
+    assert_ne!(account.rwasm_bytecode_hash, POSEIDON_EMPTY);
+}
+
+#[test]
+fn test_evm_nested_wasm_create() {
+    let _ctx = TestingContext::default();
+    let mut env = Env::default();
+    const DEPLOYER_ADDRESS: Address = Address::ZERO;
+    env.tx.caller = DEPLOYER_ADDRESS;
+    env.tx.transact_to = TransactTo::Create(CreateScheme::Create);
+    // calldatacopy(0, 0, calldatasize); mstore(0, create(0, 0, calldatasize)); return(0, 32)
+    let runtime_code = "365f5f37365f5ff05f5260205ff3";
+    // codecopy(0, 9, 0x0e); return(0, 0x0e)
+    env.tx.data = hex::decode(format!("600e8060095f395ff3{runtime_code}"))
+        .unwrap()
+        .into();
+    env.tx.gas_limit = 3_000_000;
+    let mut evm = EVM::with_env(env);
+    let result = evm.transact().unwrap();
+    assert!(result.result.is_success());
+    let factory_address = calc_create_address(&DEPLOYER_ADDRESS, 0);
+    // factory deploys WASM binary from the call data through WCL
+    let wasm = wat::parse_str(
+        r#"
+(module
+  (func $deploy)
+  (func $main)
+  (export "deploy" (func $deploy))
+  (export "main" (func $main)))
+    "#,
+    )
+    .unwrap();
+    let mut env = Env::default();
+    env.tx.transact_to = TransactTo::Call(factory_address);
+    env.tx.data = wasm.clone().into();
+    env.tx.gas_limit = 10_000_000;
+    let mut evm = EVM::with_env(env);
+    let result = evm.transact().unwrap();
+    assert!(result.result.is_success());
+    // code deposit is charged as fuel of WCL, so it's included into the gas used by the caller
+    assert!(result.result.gas_used() > code_deposit_cost(wasm.len()));
+    let output = result.result.output().unwrap_or_default();
+    assert_eq!(output.len(), 32);
+    let created_address = Address::from_slice(&output[12..]);
+    assert_ne!(created_address, Address::ZERO);
+    let account = Account::new_from_jzkt(&created_address);
+    assert_eq!(account.source_bytecode_size, wasm.len() as u64);
+    assert_ne!(account.rwasm_bytecode_hash, POSEIDON_EMPTY);
+}
+
+#[test]
+fn test_state_diff_and_logs() {
+    let _ctx = TestingContext::default();
+    let mut env = Env::default();
+    const DEPLOYER_ADDRESS: Address = Address::ZERO;
+    env.tx.caller = DEPLOYER_ADDRESS;
+    env.tx.transact_to = TransactTo::Create(CreateScheme::Create);
+    // sstore(0, 0x2a); log1(0, 0, 0x01); return(0, 0)
+    env.tx.data = Bytes::from_static(&hex!("602a6000556001600060006000a160006000f3"));
+    env.tx.gas_limit = 3_000_000;
+    let mut evm = EVM::with_env(env);
+    let result = evm.transact().unwrap();
+    assert!(result.result.is_success());
+    let contract_address = calc_create_address(&DEPLOYER_ADDRESS, 0);
+    // new account must be marked as created
+    let account = result.state.get(&contract_address).unwrap();
+    assert!(account.is_created());
+    // log is emitted by the created contract
+    let logs = result.result.logs();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].address, contract_address);
+    assert_eq!(logs[0].topics(), &[B256::with_last_byte(1)]);
+}
+
+#[test]
+fn test_block_executor() {
+    let ctx = TestingContext::default();
+    const DEPLOYER_ADDRESS: Address = Address::ZERO;
+    let deploy_tx = TxEnv {
+        caller: DEPLOYER_ADDRESS,
+        transact_to: TransactTo::Create(CreateScheme::Create),
+        // sstore(0, 0x2a); log1(0, 0, 0x01); return(0, 0)
+        data: Bytes::from_static(&hex!("602a6000556001600060006000a160006000f3")),
+        gas_limit: 3_000_000,
+        ..Default::default()
+    };
+    let call_tx = TxEnv {
+        transact_to: TransactTo::Call(EXAMPLE_GREETING_ADDRESS),
+        gas_limit: 3_000_000,
+        ..Default::default()
+    };
+    let transactions = [deploy_tx, call_tx]
+        .into_iter()
+        .enumerate()
+        .map(|(i, tx)| BlockTransaction {
+            tx,
+            encoded: Bytes::from(vec![i as u8]),
+        })
+        .collect::<Vec<_>>();
+    let mut executor = BlockExecutor::new(CfgEnv::default(), BlockEnv::default(), ctx.jzkt);
+    let result = executor.execute(&transactions).unwrap();
+    assert_eq!(result.receipts.len(), 2);
+    assert!(result.receipts.iter().all(|receipt| receipt.success));
+    // gas is accumulated
+    let first_gas_used = result.receipts[0].cumulative_gas_used;
+    assert!(first_gas_used > 0);
+    assert!(result.receipts[1].cumulative_gas_used > first_gas_used);
+    assert_eq!(result.gas_used, result.receipts[1].cumulative_gas_used);
+    // only the first transaction emits log
+    let contract_address = calc_create_address(&DEPLOYER_ADDRESS, 0);
+    assert_eq!(result.receipts[0].logs.len(), 1);
+    assert!(result.receipts[1].logs.is_empty());
+    assert!(result
+        .logs_bloom
+        .contains_raw_log(contract_address, &[B256::with_last_byte(1)]));
+    assert_ne!(result.receipts_root, result.transactions_root);
+    assert_ne!(result.state_root, B256::ZERO);
+}
+
+#[test]
+fn test_block_executor_rejects_invalid_transaction() {
+    let ctx = TestingContext::default();
+    let deploy_tx = TxEnv {
+        caller: Address::ZERO,
+        transact_to: TransactTo::Create(CreateScheme::Create),
+        // sstore(0, 0x2a); return(0, 0)
+        data: Bytes::from_static(&hex!("602a60005560006000f3")),
+        gas_limit: 3_000_000,
+        ..Default::default()
+    };
+    let transactions = vec![deploy_tx.clone(), deploy_tx]
+        .into_iter()
+        .map(|tx| BlockTransaction {
+            tx,
+            encoded: Bytes::new(),
+        })
+        .collect::<Vec<_>>();
+    let block = BlockEnv {
+        gas_limit: U256::from(5_000_000),
+        ..Default::default()
+    };
+    let mut executor = BlockExecutor::new(CfgEnv::default(), block, ctx.jzkt.clone());
+    let result = executor.execute(&transactions).unwrap();
+    // the second transaction doesn't fit into the block gas limit, so it gets a failed receipt
+    assert_eq!(
+        result.rejected_transactions,
+        vec![(1, InvalidTransaction::CallerGasLimitMoreThanBlock.into())]
+    );
+    assert_eq!(result.receipts.len(), 2);
+    assert!(result.receipts[0].success);
+    assert!(!result.receipts[1].success);
+    assert_eq!(
+        result.receipts[1].cumulative_gas_used,
+        result.receipts[0].cumulative_gas_used
+    );
+    assert_eq!(result.gas_used, result.receipts[0].cumulative_gas_used);
+    // changes of the first transaction are kept
+    let contract_address = calc_create_address(&Address::ZERO, 0);
+    assert_eq!(Account::new_from_jzkt(&contract_address).nonce, 1);
+}
+
+#[test]
+fn test_invalid_transactions() {
+    let _ctx = TestingContext::default();
+    const CALLER_ADDRESS: Address = address!("1000000000000000000000000000000000000001");
+    let transact = |tx: TxEnv| {
+        let mut env = Env::default();
+        env.tx = tx;
+        EVM::with_env(env).transact().unwrap_err()
+    };
+    let default_tx = TxEnv {
+        caller: CALLER_ADDRESS,
+        transact_to: TransactTo::Call(EXAMPLE_GREETING_ADDRESS),
+        gas_limit: 3_000_000,
+        ..Default::default()
+    };
+    // nonce must match account's nonce
+    assert_eq!(
+        transact(TxEnv {
+            nonce: Some(5),
+            ..default_tx.clone()
+        }),
+        InvalidTransaction::NonceTooHigh { tx: 5, state: 0 }.into()
+    );
+    // caller can't pay for the value
+    assert_eq!(
+        transact(TxEnv {
+            value: U256::from(1),
+            ..default_tx.clone()
+        }),
+        InvalidTransaction::LackOfFundForMaxFee {
+            fee: Box::new(U256::from(1)),
+            balance: Box::new(U256::ZERO),
+        }
+        .into()
+    );
+    // EIP-3607: caller can't have code
+    assert_eq!(
+        transact(TxEnv {
+            caller: EXAMPLE_GREETING_ADDRESS,
+            ..default_tx.clone()
+        }),
+        InvalidTransaction::RejectCallerWithCode.into()
+    );
+    // gas limit doesn't cover intrinsic gas
+    assert_eq!(
+        transact(TxEnv {
+            gas_limit: 20_000,
+            ..default_tx.clone()
+        }),
+        InvalidTransaction::CallGasCostMoreThanGasLimit.into()
+    );
+}
+
+#[test]
+fn test_call_tracer() {
+    let _ctx = TestingContext::default();
+    let mut env = Env::default();
+    env.tx.transact_to = TransactTo::Call(EXAMPLE_GREETING_ADDRESS);
+    env.tx.data = Bytes::from_static(&[0x01, 0x02, 0x03, 0x04]);
+    env.tx.gas_limit = 3_000_000;
+    let mut tracer = CallTracer::new(true);
+    let result = EVM::with_env(env).inspect(&mut tracer).unwrap();
+    assert!(result.result.is_success());
+    let root = tracer.root().unwrap();
+    assert_eq!(root.typ, "CALL");
+    assert_eq!(root.to, Some(EXAMPLE_GREETING_ADDRESS));
+    assert_eq!(root.output.as_ref(), "Hello, World".as_bytes());
+    assert_eq!(root.error, None);
+    assert!(root.gas_used > 0);
+    // the same transaction for 4byte tracer
+    let mut env = Env::default();
+    env.tx.transact_to = TransactTo::Call(EXAMPLE_GREETING_ADDRESS);
+    env.tx.data = Bytes::from_static(&[0x01, 0x02, 0x03, 0x04]);
+    env.tx.gas_limit = 3_000_000;
+    let mut tracer = FourByteTracer::default();
+    EVM::with_env(env).inspect(&mut tracer).unwrap();
+    assert_eq!(tracer.ids().get("0x01020304-0"), Some(&1));
+}
+
+#[test]
+fn test_call_tracer_nested_frames() {
+    let _ctx = TestingContext::default();
+    const DEPLOYER_ADDRESS: Address = Address::ZERO;
+    let deploy = |nonce: u64, runtime_code: &str| {
+        let mut env = Env::default();
+        env.tx.caller = DEPLOYER_ADDRESS;
+        env.tx.transact_to = TransactTo::Create(CreateScheme::Create);
+        // codecopy(0, 9, len); return(0, len)
+        let len = runtime_code.len() / 2;
+        env.tx.data = hex::decode(format!("60{len:02x}8060095f395ff3{runtime_code}"))
+            .unwrap()
+            .into();
+        env.tx.gas_limit = 3_000_000;
+        assert!(EVM::with_env(env).transact().unwrap().result.is_success());
+        calc_create_address(&DEPLOYER_ADDRESS, nonce)
+    };
+    // call(gas, greeting, 0, 0, 0, 0, 0); returndatacopy(0, 0, returndatasize);
+    // return(0, returndatasize)
+    let caller_address = deploy(
+        0,
+        &format!(
+            "5f5f5f5f5f73{}5af1503d5f5f3e3d5ff3",
+            hex::encode(EXAMPLE_GREETING_ADDRESS)
+        ),
+    );
+    // mstore(0, 0x60006000f3); mstore(0, create(0, 27, 5)); return(0, 32)
+    let factory_address = deploy(1, "6460006000f35f526005601b5ff05f5260205ff3");
+    // nested call of the WASM contract is reported inside the root frame
+    let mut env = Env::default();
+    env.tx.transact_to = TransactTo::Call(caller_address);
+    env.tx.gas_limit = 10_000_000;
+    let mut tracer = CallTracer::new(false);
+    let result = EVM::with_env(env).inspect(&mut tracer).unwrap();
+    assert!(result.result.is_success());
+    let root = tracer.root().unwrap();
+    assert_eq!(root.to, Some(caller_address));
+    assert_eq!(root.calls.len(), 1);
+    let call = &root.calls[0];
+    assert_eq!(call.typ, "CALL");
+    assert_eq!(call.from, caller_address);
+    assert_eq!(call.to, Some(EXAMPLE_GREETING_ADDRESS));
+    assert_eq!(call.output.as_ref(), "Hello, World".as_bytes());
+    assert!(call.gas_used > 0 && call.gas_used <= root.gas_used);
+    // nested create is reported with the created address
+    let mut env = Env::default();
+    env.tx.transact_to = TransactTo::Call(factory_address);
+    env.tx.gas_limit = 10_000_000;
+    let mut tracer = CallTracer::new(false);
+    let result = EVM::with_env(env).inspect(&mut tracer).unwrap();
+    assert!(result.result.is_success());
+    let output = result.result.output().unwrap_or_default();
+    let root = tracer.root().unwrap();
+    assert_eq!(root.calls.len(), 1);
+    let create = &root.calls[0];
+    assert_eq!(create.typ, "CREATE");
+    assert_eq!(create.from, factory_address);
+    assert_eq!(create.to, Some(Address::from_slice(&output[12..])));
+    assert_eq!(create.input.as_ref(), hex!("60006000f3"));
+    assert_eq!(create.error, None);
+}
+
+#[test]
+fn test_spec_id_from_chain_config() {
+    let ctx = TestingContext::default();
+    // devnet activates all forks at genesis
+    let mut env = Env::default();
+    env.tx.transact_to = TransactTo::Call(EXAMPLE_GREETING_ADDRESS);
+    env.tx.gas_limit = 3_000_000;
+    let mut evm = EVM::with_chain_config(env, &ctx.genesis.config);
+    assert_eq!(evm.spec_id, SpecId::CANCUN);
+    assert!(evm.transact().unwrap().result.is_success());
+    // forks before the merge are activated by block number and after by timestamp
+    let chain_config = ChainConfig {
+        homestead_block: Some(0),
+        london_block: Some(10),
+        shanghai_time: Some(100),
+        ..Default::default()
+    };
+    assert_eq!(
+        spec_id_from_chain_config(&chain_config, 5, 0),
+        SpecId::HOMESTEAD
+    );
+    assert_eq!(
+        spec_id_from_chain_config(&chain_config, 10, 50),
+        SpecId::LONDON
+    );
+    assert_eq!(
+        spec_id_from_chain_config(&chain_config, 20, 100),
+        SpecId::SHANGHAI
+    );
+    assert_eq!(
+        spec_id_from_chain_config(&ChainConfig::default(), 100, 100),
+        SpecId::FRONTIER
+    );
+}
@@ -0,0 +1,397 @@
+use crate::{
+    inspector::{CallInputs, CallOutcome, CreateInputs, CreateOutcome, Inspector},
+    EVMData,
+};
+use fluentbase_sdk::call::CallScheme;
+use fluentbase_types::{Address, Bytes, ExitCode, U256};
+use revm_primitives::{hex, Log};
+use serde_json::{json, Map, Value};
+use std::collections::BTreeMap;
+
+/// Frame of the call tracer, it's encoded in the same way as geth's `callTracer` does it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallFrame {
+    /// One of `CALL`, `STATICCALL`, `DELEGATECALL`, `CALLCODE`, `CREATE`, `CREATE2` or
+    /// `SELFDESTRUCT`
+    pub typ: &'static str,
+    pub from: Address,
+    /// Callee or created address, it's empty for failed creates
+    pub to: Option<Address>,
+    pub value: U256,
+    pub gas: u64,
+    pub gas_used: u64,
+    pub input: Bytes,
+    pub output: Bytes,
+    pub error: Option<String>,
+    pub logs: Vec<Log>,
+    pub calls: Vec<CallFrame>,
+}
+
+impl CallFrame {
+    pub fn to_json(&self) -> Value {
+        let mut result = Map::new();
+        result.insert("type".into(), json!(self.typ));
+        result.insert("from".into(), json!(hex::encode_prefixed(self.from)));
+        if let Some(to) = self.to {
+            result.insert("to".into(), json!(hex::encode_prefixed(to)));
+        }
+        if self.typ != "STATICCALL" && self.typ != "DELEGATECALL" {
+            result.insert("value".into(), json!(format!("{:#x}", self.value)));
+        }
+        result.insert("gas".into(), json!(format!("{:#x}", self.gas)));
+        result.insert("gasUsed".into(), json!(format!("{:#x}", self.gas_used)));
+        result.insert("input".into(), json!(hex::encode_prefixed(&self.input)));
+        if !self.output.is_empty() {
+            result.insert("output".into(), json!(hex::encode_prefixed(&self.output)));
+        }
+        if let Some(error) = &self.error {
+            result.insert("error".into(), json!(error));
+        }
+        if !self.logs.is_empty() {
+            let logs = self
+                .logs
+                .iter()
+                .map(|log| {
+                    json!({
+                        "address": hex::encode_prefixed(log.address),
+                        "topics": log.topics().iter().map(hex::encode_prefixed).collect::<Vec<_>>(),
+                        "data": hex::encode_prefixed(&log.data.data),
+                    })
+                })
+                .collect::<Vec<_>>();
+            result.insert("logs".into(), json!(logs));
+        }
+        if !self.calls.is_empty() {
+            let calls = self
+                .calls
+                .iter()
+                .map(CallFrame::to_json)
+                .collect::<Vec<_>>();
+            result.insert("calls".into(), json!(calls));
+        }
+        Value::Object(result)
+    }
+}
+
+/// Maps exit code into geth's error message
+fn error_message(result: i32) -> Option<String> {
+    if result == ExitCode::Ok.into_i32() {
+        None
+    } else if result == ExitCode::Panic.into_i32() {
+        Some("execution reverted".into())
+    } else if result == ExitCode::OutOfFuel.into_i32() {
+        Some("out of gas".into())
+    } else {
+        Some(format!("execution failed with exit code {}", result))
+    }
+}
+
+/// Call tracer that is compatible with geth's `callTracer`
+#[derive(Debug, Default)]
+pub struct CallTracer {
+    with_log: bool,
+    stack: Vec<CallFrame>,
+    root: Option<CallFrame>,
+}
+
+impl CallTracer {
+    /// Creates new tracer, logs are collected only if `with_log` is set (the same as geth's
+    /// `withLog` option)
+    pub fn new(with_log: bool) -> Self {
+        Self {
+            with_log,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the root frame of the traced transaction
+    pub fn root(&self) -> Option<&CallFrame> {
+        self.root.as_ref()
+    }
+
+    pub fn to_json(&self) -> Value {
+        self.root
+            .as_ref()
+            .map(CallFrame::to_json)
+            .unwrap_or(Value::Null)
+    }
+
+    fn enter(&mut self, frame: CallFrame) {
+        self.stack.push(frame);
+    }
+
+    fn exit(&mut self, result: i32, to: Option<Address>, output: Bytes, gas_used: u64) {
+        let Some(mut frame) = self.stack.pop() else {
+            return;
+        };
+        if to.is_some() {
+            frame.to = to;
+        }
+        frame.output = output;
+        frame.gas_used = gas_used;
+        frame.error = error_message(result);
+        match self.stack.last_mut() {
+            Some(parent) => parent.calls.push(frame),
+            None => self.root = Some(frame),
+        }
+    }
+}
+
+impl Inspector for CallTracer {
+    fn call(&mut self, _data: &mut EVMData<'_>, inputs: &CallInputs) {
+        self.enter(CallFrame {
+            typ: match inputs.scheme {
+                CallScheme::Call => "CALL",
+                CallScheme::StaticCall => "STATICCALL",
+                CallScheme::DelegateCall => "DELEGATECALL",
+                CallScheme::CallCode => "CALLCODE",
+            },
+            from: inputs.caller,
+            to: Some(inputs.address),
+            value: inputs.value,
+            gas: inputs.gas_limit,
+            gas_used: 0,
+            input: inputs.input.clone(),
+            output: Bytes::new(),
+            error: None,
+            logs: vec![],
+            calls: vec![],
+        });
+    }
+
+    fn call_end(&mut self, _data: &mut EVMData<'_>, _inputs: &CallInputs, outcome: &CallOutcome) {
+        self.exit(
+            outcome.result,
+            None,
+            outcome.output.clone(),
+            outcome.gas_used,
+        );
+    }
+
+    fn create(&mut self, _data: &mut EVMData<'_>, inputs: &CreateInputs) {
+        self.enter(CallFrame {
+            typ: if inputs.salt.is_some() {
+                "CREATE2"
+            } else {
+                "CREATE"
+            },
+            from: inputs.caller,
+            to: None,
+            value: inputs.value,
+            gas: inputs.gas_limit,
+            gas_used: 0,
+            input: inputs.init_code.clone(),
+            output: Bytes::new(),
+            error: None,
+            logs: vec![],
+            calls: vec![],
+        });
+    }
+
+    fn create_end(
+        &mut self,
+        _data: &mut EVMData<'_>,
+        _inputs: &CreateInputs,
+        outcome: &CreateOutcome,
+    ) {
+        self.exit(
+            outcome.result,
+            outcome.address,
+            outcome.output.clone(),
+            outcome.gas_used,
+        );
+    }
+
+    fn log(&mut self, _data: &mut EVMData<'_>, log: &Log) {
+        if !self.with_log {
+            return;
+        }
+        if let Some(frame) = self.stack.last_mut() {
+            frame.logs.push(log.clone());
+        }
+    }
+
+    fn selfdestruct(&mut self, _data: &mut EVMData<'_>, contract: Address, value: U256) {
+        let Some(parent) = self.stack.last_mut() else {
+            return;
+        };
+        // beneficiary isn't known, because the account is removed from the trie
+        parent.calls.push(CallFrame {
+            typ: "SELFDESTRUCT",
+            from: contract,
+            to: None,
+            value,
+            gas: 0,
+            gas_used: 0,
+            input: Bytes::new(),
+            output: Bytes::new(),
+            error: None,
+            logs: vec![],
+            calls: vec![],
+        });
+    }
+}
+
+/// Tracer that counts function selectors and calldata sizes, it's compatible with geth's
+/// `4byteTracer`
+#[derive(Debug, Default)]
+pub struct FourByteTracer {
+    ids: BTreeMap<String, u64>,
+}
+
+impl FourByteTracer {
+    /// Returns collected ids in the format of `0x{selector}-{calldata size w/o selector}`
+    pub fn ids(&self) -> &BTreeMap<String, u64> {
+        &self.ids
+    }
+
+    pub fn to_json(&self) -> Value {
+        json!(self.ids)
+    }
+}
+
+impl Inspector for FourByteTracer {
+    fn call(&mut self, _data: &mut EVMData<'_>, inputs: &CallInputs) {
+        if inputs.input.len() < 4 {
+            return;
+        }
+        let id = format!(
+            "{}-{}",
+            hex::encode_prefixed(&inputs.input[0..4]),
+            inputs.input.len() - 4
+        );
+        *self.ids.entry(id).or_default() += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use revm_primitives::Env;
+
+    const CALLER: Address = Address::with_last_byte(1);
+    const CALLEE: Address = Address::with_last_byte(2);
+
+    fn call_inputs(input: &'static [u8]) -> CallInputs {
+        CallInputs {
+            scheme: CallScheme::Call,
+            caller: CALLER,
+            address: CALLEE,
+            value: U256::from(10),
+            input: Bytes::from_static(input),
+            gas_limit: 100_000,
+            is_static: false,
+        }
+    }
+
+    #[test]
+    fn test_call_tracer() {
+        let mut env = Env::default();
+        let mut data = EVMData {
+            env: &mut env,
+            journal_checkpoint: 0,
+        };
+        let mut tracer = CallTracer::new(true);
+        let inputs = call_inputs(&[0x01, 0x02, 0x03, 0x04]);
+        tracer.call(&mut data, &inputs);
+        tracer.log(
+            &mut data,
+            &Log::new_unchecked(CALLEE, vec![], Bytes::from_static(&[0xff])),
+        );
+        tracer.call_end(
+            &mut data,
+            &inputs,
+            &CallOutcome {
+                result: ExitCode::Panic.into_i32(),
+                output: Bytes::from_static(&[0xaa]),
+                gas_used: 21000,
+            },
+        );
+        assert_eq!(
+            tracer.to_json(),
+            json!({
+                "type": "CALL",
+                "from": "0x0000000000000000000000000000000000000001",
+                "to": "0x0000000000000000000000000000000000000002",
+                "value": "0xa",
+                "gas": "0x186a0",
+                "gasUsed": "0x5208",
+                "input": "0x01020304",
+                "output": "0xaa",
+                "error": "execution reverted",
+                "logs": [{
+                    "address": "0x0000000000000000000000000000000000000002",
+                    "topics": [],
+                    "data": "0xff",
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn test_call_tracer_nested_frames() {
+        let mut env = Env::default();
+        let mut data = EVMData {
+            env: &mut env,
+            journal_checkpoint: 0,
+        };
+        let mut tracer = CallTracer::new(false);
+        let inputs = call_inputs(&[]);
+        tracer.call(&mut data, &inputs);
+        let delegate_inputs = CallInputs {
+            scheme: CallScheme::DelegateCall,
+            ..call_inputs(&[])
+        };
+        tracer.call(&mut data, &delegate_inputs);
+        let ok = CallOutcome {
+            result: ExitCode::Ok.into_i32(),
+            output: Bytes::new(),
+            gas_used: 0,
+        };
+        tracer.call_end(&mut data, &delegate_inputs, &ok);
+        let create_inputs = CreateInputs {
+            caller: CALLEE,
+            value: U256::ZERO,
+            init_code: Bytes::from_static(&[0x00]),
+            gas_limit: 1000,
+            salt: None,
+        };
+        tracer.create(&mut data, &create_inputs);
+        tracer.create_end(
+            &mut data,
+            &create_inputs,
+            &CreateOutcome {
+                result: ExitCode::Ok.into_i32(),
+                address: Some(Address::with_last_byte(3)),
+                output: Bytes::from_static(&[0xfe]),
+                gas_used: 0,
+            },
+        );
+        tracer.selfdestruct(&mut data, CALLEE, U256::from(10));
+        tracer.call_end(&mut data, &inputs, &ok);
+        let calls = &tracer.root().unwrap().calls;
+        assert_eq!(
+            calls.iter().map(|frame| frame.typ).collect::<Vec<_>>(),
+            vec!["DELEGATECALL", "CREATE", "SELFDESTRUCT"]
+        );
+        // runtime code of the deployed contract is an output of the create
+        assert_eq!(calls[1].to, Some(Address::with_last_byte(3)));
+        assert_eq!(calls[1].output, Bytes::from_static(&[0xfe]));
+        assert_eq!(calls[2].from, CALLEE);
+        assert_eq!(calls[2].value, U256::from(10));
+    }
+
+    #[test]
+    fn test_4byte_tracer() {
+        let mut env = Env::default();
+        let mut data = EVMData {
+            env: &mut env,
+            journal_checkpoint: 0,
+        };
+        let mut tracer = FourByteTracer::default();
+        tracer.call(&mut data, &call_inputs(&[0x01, 0x02, 0x03, 0x04, 0x05]));
+        tracer.call(&mut data, &call_inputs(&[0x01, 0x02, 0x03, 0x04, 0x06]));
+        tracer.call(&mut data, &call_inputs(&[0x01, 0x02]));
+        assert_eq!(tracer.to_json(), json!({ "0x01020304-1": 2 }));
+    }
+}
@@ -7,16 +7,20 @@ pub mod jzkt_commit;
 pub mod jzkt_compute_root;
 pub mod jzkt_emit_log;
 pub mod jzkt_get;
+pub mod jzkt_load;
 pub mod jzkt_open;
 pub mod jzkt_preimage_copy;
+pub mod jzkt_preimage_read;
 pub mod jzkt_preimage_size;
 pub mod jzkt_remove;
 pub mod jzkt_rollback;
+pub mod jzkt_store;
 pub mod jzkt_update;
 pub mod jzkt_update_preimage;
 pub mod sys_exec;
 pub mod sys_exec_hash;
 pub mod sys_forward_output;
+pub mod sys_fuel;
 pub mod sys_halt;
 pub mod sys_input_size;
 pub mod sys_output_size;
@@ -37,16 +41,20 @@ use crate::{
         jzkt_compute_root::JzktComputeRoot,
         jzkt_emit_log::JzktEmitLog,
         jzkt_get::JzktGet,
+        jzkt_load::JzktLoad,
         jzkt_open::JzktOpen,
         jzkt_preimage_copy::JzktPreimageCopy,
+        jzkt_preimage_read::JzktPreimageRead,
         jzkt_preimage_size::JzktPreimageSize,
         jzkt_remove::JzktRemove,
         jzkt_rollback::JzktRollback,
+        jzkt_store::JzktStore,
         jzkt_update::JzktUpdate,
         jzkt_update_preimage::JzktUpdatePreimage,
         sys_exec::SysExec,
         sys_exec_hash::SysExecHash,
         sys_forward_output::SysForwardOutput,
+        sys_fuel::SysFuel,
         sys_halt::SysHalt,
         sys_input_size::SysInputSize,
         sys_output_size::SysOutputSize,
@@ -57,8 +65,8 @@ use crate::{
     },
     runtime::RuntimeContext,
 };
-use fluentbase_types::SysFuncIdx;
-use rwasm::{Caller, Linker, Store};
+use fluentbase_types::{ExitCode, SysFuncIdx};
+use rwasm::{core::Trap, Caller, Linker, Store};
 
 pub trait RuntimeHandler {
     const MODULE_NAME: &'static str;
@@ -86,6 +94,7 @@ impl_runtime_handler!(SysState, SYS_STATE, fn fluentbase_v1alpha::_sys_state() -
 impl_runtime_handler!(SysExec, SYS_EXEC, fn fluentbase_v1alpha::_sys_exec(code_offset: u32, code_len: u32, input_offset: u32, input_len: u32, return_offset: u32, return_len: u32, fuel_offset: u32, state: u32) -> i32);
 impl_runtime_handler!(SysExecHash, SYS_EXEC_HASH, fn fluentbase_v1alpha::_sys_exec_hash(code_hash32_offset: u32, input_offset: u32, input_len: u32, return_offset: u32, return_len: u32, fuel_offset: u32, state: u32) -> i32);
 impl_runtime_handler!(SysForwardOutput, SYS_FORWARD_OUTPUT, fn fluentbase_v1alpha::_sys_forward_output(offset: u32, len: u32) -> ());
+impl_runtime_handler!(SysFuel, SYS_FUEL, fn fluentbase_v1alpha::_sys_fuel(delta: u64) -> ());
 
 impl_runtime_handler!(JzktOpen, JZKT_OPEN, fn fluentbase_v1alpha::_zktrie_open(root32_offset: u32) -> ());
 impl_runtime_handler!(JzktCheckpoint, JZKT_CHECKPOINT, fn fluentbase_v1alpha::_jzkt_checkpoint() -> u64);
@@ -96,10 +105,34 @@ impl_runtime_handler!(JzktComputeRoot, JZKT_COMPUTE_ROOT, fn fluentbase_v1alpha:
 impl_runtime_handler!(JzktEmitLog, JZKT_EMIT_LOG, fn fluentbase_v1alpha::_jzkt_emit_log(key32_ptr: u32, topics32s_ptr: u32, topics32s_len: u32, data_ptr: u32, data_len: u32) -> ());
 impl_runtime_handler!(JzktCommit, JZKT_COMMIT, fn fluentbase_v1alpha::_jzkt_commit(root32_offset: u32) -> ());
 impl_runtime_handler!(JzktRollback, JZKT_ROLLBACK, fn fluentbase_v1alpha::_jzkt_rollback(checkpoint: u64) -> ());
+impl_runtime_handler!(JzktStore, JZKT_STORE, fn fluentbase_v1alpha::_jzkt_store(address32_offset: u32, slot32_offset: u32, value32_offset: u32) -> ());
+impl_runtime_handler!(JzktLoad, JZKT_LOAD, fn fluentbase_v1alpha::_jzkt_load(address32_offset: u32, slot32_offset: u32, output32_offset: u32) -> u32);
 impl_runtime_handler!(JzktPreimageSize, JZKT_PREIMAGE_SIZE, fn fluentbase_v1alpha::_jzkt_preimage_size(hash32_ptr: u32) -> u32);
 impl_runtime_handler!(JzktPreimageCopy, JZKT_PREIMAGE_COPY, fn fluentbase_v1alpha::_jzkt_preimage_copy(hash32_ptr: u32, preimage_ptr: u32) -> ());
+impl_runtime_handler!(JzktPreimageRead, JZKT_PREIMAGE_READ, fn fluentbase_v1alpha::_jzkt_preimage_read(hash32_ptr: u32, target: u32, offset: u32, length: u32) -> ());
 impl_runtime_handler!(JzktUpdatePreimage, JZKT_UPDATE_PREIMAGE, fn fluentbase_v1alpha::_jzkt_update_preimage(key32_ptr: u32, field: u32, preimage_ptr: u32, preimage_len: u32) -> i32);
 
+/// Charges fuel for the host function call, it does nothing if fuel metering is disabled
+pub(crate) fn consume_fuel<T>(
+    caller: &mut Caller<'_, RuntimeContext<T>>,
+    fuel: u64,
+) -> Result<(), Trap> {
+    if fuel == 0 || caller.fuel_consumed().is_none() {
+        return Ok(());
+    }
+    caller
+        .consume_fuel(fuel)
+        .map_err(|_| ExitCode::OutOfFuel.into_trap())?;
+    Ok(())
+}
+
+/// Returns fuel that is left in the caller's store, `None` if fuel metering is disabled
+pub(crate) fn remaining_fuel<T>(caller: &Caller<'_, RuntimeContext<T>>) -> Option<u64> {
+    caller
+        .fuel_consumed()
+        .map(|fuel_consumed| caller.data().fuel_limit.saturating_sub(fuel_consumed))
+}
+
 fn runtime_register_handlers<'t, T, const IS_SOVEREIGN: bool>(
     linker: &mut Linker<RuntimeContext<'t, T>>,
     store: &mut Store<RuntimeContext<'t, T>>,
@@ -118,6 +151,7 @@ fn runtime_register_handlers<'t, T, const IS_SOVEREIGN: bool>(
     SysExec::register_handler(linker, store);
     SysExecHash::register_handler(linker, store);
     SysState::register_handler(linker, store);
+    SysFuel::register_handler(linker, store);
     if IS_SOVEREIGN {
         JzktOpen::register_handler(linker, store);
         JzktCheckpoint::register_handler(linker, store);
@@ -126,16 +160,19 @@ fn runtime_register_handlers<'t, T, const IS_SOVEREIGN: bool>(
         JzktComputeRoot::register_handler(linker, store);
     }
     JzktGet::register_handler(linker, store);
+    JzktLoad::register_handler(linker, store);
     JzktEmitLog::register_handler(linker, store);
     if IS_SOVEREIGN {
         JzktCommit::register_handler(linker, store);
         JzktRollback::register_handler(linker, store);
+        JzktStore::register_handler(linker, store);
     }
     if IS_SOVEREIGN {
         JzktPreimageSize::register_handler(linker, store);
         JzktUpdatePreimage::register_handler(linker, store);
     }
     JzktPreimageCopy::register_handler(linker, store);
+    JzktPreimageRead::register_handler(linker, store);
 }
 
 pub fn runtime_register_sovereign_handlers<'t, T>(
@@ -1,4 +1,5 @@
-use crate::RuntimeContext;
+use crate::{instruction::consume_fuel, RuntimeContext};
+use fluentbase_types::SysFuncIdx;
 use rwasm::{core::Trap, Caller};
 
 pub struct CryptoKeccak256;
@@ -10,6 +11,12 @@ impl CryptoKeccak256 {
         data_len: u32,
         output_offset: u32,
     ) -> Result<(), Trap> {
+        let fuel = caller
+            .data()
+            .fuel_schedule()
+            .sys_func_fuel(SysFuncIdx::CRYPTO_KECCAK256)
+            .word_cost(data_len as u64);
+        consume_fuel(&mut caller, fuel)?;
         let data = caller.read_memory(data_offset, data_len)?;
         caller.write_memory(output_offset, &Self::fn_impl(data))?;
         Ok(())
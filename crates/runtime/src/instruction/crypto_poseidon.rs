@@ -1,4 +1,5 @@
-use crate::RuntimeContext;
+use crate::{instruction::consume_fuel, RuntimeContext};
+use fluentbase_types::SysFuncIdx;
 use rwasm::{core::Trap, Caller};
 
 pub struct CryptoPoseidon;
@@ -10,6 +11,12 @@ impl CryptoPoseidon {
         f32s_len: u32,
         output_offset: u32,
     ) -> Result<(), Trap> {
+        let fuel = caller
+            .data()
+            .fuel_schedule()
+            .sys_func_fuel(SysFuncIdx::CRYPTO_POSEIDON)
+            .word_cost(f32s_len as u64);
+        consume_fuel(&mut caller, fuel)?;
         let data = caller.read_memory(f32s_offset, f32s_len)?;
         caller.write_memory(output_offset, &Self::fn_impl(data))?;
         Ok(())
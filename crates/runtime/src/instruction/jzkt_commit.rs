@@ -15,6 +15,9 @@ impl JzktCommit {
     }
 
     pub fn fn_impl<T>(ctx: &mut RuntimeContext<T>) -> Result<[u8; 32], ExitCode> {
+        if ctx.is_static {
+            return Err(ExitCode::WriteProtection);
+        }
         let jzkt = ctx.jzkt.clone().unwrap();
         let (root, _logs) = jzkt.borrow_mut().commit()?;
         Ok(root)
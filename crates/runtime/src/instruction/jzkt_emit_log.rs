@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::{Address, Bytes, B256};
+use crate::{instruction::consume_fuel, RuntimeContext};
+use fluentbase_types::{Address, Bytes, ExitCode, SysFuncIdx, B256};
 use rwasm::{core::Trap, Caller};
 
 pub struct JzktEmitLog;
@@ -13,9 +13,15 @@ impl JzktEmitLog {
         data_ptr: u32,
         data_len: u32,
     ) -> Result<(), Trap> {
+        let fuel = caller
+            .data()
+            .fuel_schedule()
+            .sys_func_fuel(SysFuncIdx::JZKT_EMIT_LOG)
+            .word_cost(topics32s_len as u64 * 32 + data_len as u64);
+        consume_fuel(&mut caller, fuel)?;
         let key = caller.read_memory(key32_ptr, 32)?.to_vec();
         let topics = caller
-            .read_memory(topics32s_ptr, topics32s_len)?
+            .read_memory(topics32s_ptr, topics32s_len * 32)?
             .chunks(32)
             .map(|v| {
                 let mut res = B256::ZERO;
@@ -24,7 +30,7 @@ impl JzktEmitLog {
             })
             .collect::<Vec<_>>();
         let data = caller.read_memory(data_ptr, data_len)?.to_vec();
-        Self::fn_impl(caller.data_mut(), &key, &topics, &data);
+        Self::fn_impl(caller.data_mut(), &key, &topics, &data).map_err(|err| err.into_trap())?;
         Ok(())
     }
 
@@ -33,12 +39,16 @@ impl JzktEmitLog {
         key: &[u8],
         topics: &Vec<B256>,
         data: &[u8],
-    ) {
+    ) -> Result<(), ExitCode> {
+        if context.is_static {
+            return Err(ExitCode::WriteProtection);
+        }
         let jzkt = context.jzkt.clone().unwrap();
         jzkt.borrow_mut().emit_log(
-            Address::from_slice(key),
+            Address::from_slice(&key[12..]),
             topics.clone(),
             Bytes::copy_from_slice(data),
         );
+        Ok(())
     }
 }
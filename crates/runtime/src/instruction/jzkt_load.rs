@@ -0,0 +1,36 @@
+use crate::RuntimeContext;
+use fluentbase_types::Address;
+use rwasm::{core::Trap, Caller};
+
+pub struct JzktLoad;
+
+impl JzktLoad {
+    pub fn fn_handler<T>(
+        mut caller: Caller<'_, RuntimeContext<T>>,
+        address32_offset: u32,
+        slot32_offset: u32,
+        output32_offset: u32,
+    ) -> Result<u32, Trap> {
+        let address = caller.read_memory(address32_offset, 32)?.to_vec();
+        let slot = caller.read_memory(slot32_offset, 32)?.to_vec();
+        let is_cold = match Self::fn_impl(caller.data_mut(), &address, &slot) {
+            Some((value, is_cold)) => {
+                caller.write_memory(output32_offset, &value)?;
+                is_cold
+            }
+            None => true,
+        };
+        Ok(is_cold as u32)
+    }
+
+    pub fn fn_impl<T>(
+        context: &mut RuntimeContext<T>,
+        address: &[u8],
+        slot: &[u8],
+    ) -> Option<([u8; 32], bool)> {
+        let jzkt = context.jzkt.clone().unwrap();
+        let address = Address::from_slice(&address[12..]);
+        let result = jzkt.borrow_mut().load(&address, slot.try_into().unwrap());
+        result
+    }
+}
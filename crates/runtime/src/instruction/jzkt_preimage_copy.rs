@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::ExitCode;
+use crate::{instruction::consume_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct JzktPreimageCopy;
@@ -12,6 +12,12 @@ impl JzktPreimageCopy {
     ) -> Result<(), Trap> {
         let hash = caller.read_memory(hash32_ptr, 32)?.to_vec();
         let preimage = Self::fn_impl(caller.data_mut(), &hash).map_err(|err| err.into_trap())?;
+        let fuel = caller
+            .data()
+            .fuel_schedule()
+            .sys_func_fuel(SysFuncIdx::JZKT_PREIMAGE_COPY)
+            .word_cost(preimage.len() as u64);
+        consume_fuel(&mut caller, fuel)?;
         caller.write_memory(preimage_ptr, &preimage)?;
         Ok(())
     }
@@ -0,0 +1,45 @@
+use crate::{instruction::consume_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, SysFuncIdx};
+use rwasm::{core::Trap, Caller};
+
+pub struct JzktPreimageRead;
+
+impl JzktPreimageRead {
+    /// Copies a part of the preimage, it lets to check a bytecode prefix (f.e. WASM magic)
+    /// without copying the whole bytecode
+    pub fn fn_handler<T>(
+        mut caller: Caller<'_, RuntimeContext<T>>,
+        hash32_ptr: u32,
+        target: u32,
+        offset: u32,
+        length: u32,
+    ) -> Result<(), Trap> {
+        let fuel = caller
+            .data()
+            .fuel_schedule()
+            .sys_func_fuel(SysFuncIdx::JZKT_PREIMAGE_READ)
+            .word_cost(length as u64);
+        consume_fuel(&mut caller, fuel)?;
+        let hash = caller.read_memory(hash32_ptr, 32)?.to_vec();
+        let preimage = Self::fn_impl(caller.data_mut(), &hash, offset, length)
+            .map_err(|err| err.into_trap())?;
+        caller.write_memory(target, &preimage)?;
+        Ok(())
+    }
+
+    pub fn fn_impl<T>(
+        ctx: &mut RuntimeContext<T>,
+        hash: &[u8],
+        offset: u32,
+        length: u32,
+    ) -> Result<Vec<u8>, ExitCode> {
+        let jzkt = ctx.jzkt.clone().unwrap();
+        let preimage = jzkt.borrow_mut().preimage(hash.try_into().unwrap());
+        let end = offset as usize + length as usize;
+        if end <= preimage.len() {
+            Ok(preimage[offset as usize..end].to_vec())
+        } else {
+            Err(ExitCode::MemoryOutOfBounds)
+        }
+    }
+}
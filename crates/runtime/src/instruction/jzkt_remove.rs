@@ -15,6 +15,9 @@ impl JzktRemove {
     }
 
     pub fn fn_impl<T>(context: &mut RuntimeContext<T>, key: &[u8]) -> Result<(), ExitCode> {
+        if context.is_static {
+            return Err(ExitCode::WriteProtection);
+        }
         let jzkt = context.jzkt.clone().unwrap();
         jzkt.borrow_mut().remove(key.try_into().unwrap());
         Ok(())
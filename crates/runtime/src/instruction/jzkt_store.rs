@@ -0,0 +1,39 @@
+use crate::RuntimeContext;
+use fluentbase_types::{Address, ExitCode};
+use rwasm::{core::Trap, Caller};
+
+pub struct JzktStore;
+
+impl JzktStore {
+    pub fn fn_handler<T>(
+        mut caller: Caller<'_, RuntimeContext<T>>,
+        address32_offset: u32,
+        slot32_offset: u32,
+        value32_offset: u32,
+    ) -> Result<(), Trap> {
+        let address = caller.read_memory(address32_offset, 32)?.to_vec();
+        let slot = caller.read_memory(slot32_offset, 32)?.to_vec();
+        let value = caller.read_memory(value32_offset, 32)?.to_vec();
+        Self::fn_impl(caller.data_mut(), &address, &slot, &value).map_err(|err| err.into_trap())?;
+        Ok(())
+    }
+
+    pub fn fn_impl<T>(
+        context: &mut RuntimeContext<T>,
+        address: &[u8],
+        slot: &[u8],
+        value: &[u8],
+    ) -> Result<(), ExitCode> {
+        if context.is_static {
+            return Err(ExitCode::WriteProtection);
+        }
+        let jzkt = context.jzkt.clone().unwrap();
+        let address = Address::from_slice(&address[12..]);
+        jzkt.borrow_mut().store(
+            &address,
+            slot.try_into().unwrap(),
+            value.try_into().unwrap(),
+        );
+        Ok(())
+    }
+}
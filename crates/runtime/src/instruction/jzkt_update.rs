@@ -32,6 +32,9 @@ impl JzktUpdate {
         value_flags: u32,
         vals: Vec<[u8; 32]>,
     ) -> Result<(), ExitCode> {
+        if context.is_static {
+            return Err(ExitCode::WriteProtection);
+        }
         let jzkt = context.jzkt.clone().unwrap();
         jzkt.borrow_mut()
             .update(key.try_into().unwrap(), &vals, value_flags);
@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::ExitCode;
+use crate::{instruction::consume_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct JzktUpdatePreimage;
@@ -12,6 +12,12 @@ impl JzktUpdatePreimage {
         preimage_ptr: u32,
         preimage_len: u32,
     ) -> Result<i32, Trap> {
+        let fuel = caller
+            .data()
+            .fuel_schedule()
+            .sys_func_fuel(SysFuncIdx::JZKT_UPDATE_PREIMAGE)
+            .word_cost(preimage_len as u64);
+        consume_fuel(&mut caller, fuel)?;
         let key = caller.read_memory(key32_ptr, 32)?.to_vec();
         let preimage = caller.read_memory(preimage_ptr, preimage_len)?.to_vec();
         let res = Self::fn_impl(caller.data_mut(), &key, field, &preimage)
@@ -25,6 +31,9 @@ impl JzktUpdatePreimage {
         field: u32,
         preimage: &[u8],
     ) -> Result<bool, ExitCode> {
+        if ctx.is_static {
+            return Err(ExitCode::WriteProtection);
+        }
         let jzkt = ctx.jzkt.clone().unwrap();
         let res = jzkt
             .borrow_mut()
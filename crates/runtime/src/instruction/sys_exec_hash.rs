@@ -1,6 +1,11 @@
-use crate::{Runtime, RuntimeContext};
+use crate::{
+    instruction::{consume_fuel, remaining_fuel},
+    JournalFrame,
+    Runtime,
+    RuntimeContext,
+};
 use byteorder::{ByteOrder, LittleEndian};
-use fluentbase_types::{ExitCode, STATE_MAIN};
+use fluentbase_types::{ExitCode, SysFuncIdx, STATE_STATIC_FLAG};
 use rwasm::{core::Trap, Caller};
 
 pub struct SysExecHash;
@@ -16,28 +21,49 @@ impl SysExecHash {
         fuel_offset: u32,
         state: u32,
     ) -> Result<i32, Trap> {
+        let fuel = caller
+            .data()
+            .fuel_schedule()
+            .sys_func_fuel(SysFuncIdx::SYS_EXEC_HASH)
+            .word_cost(input_len as u64);
+        consume_fuel(&mut caller, fuel)?;
         let bytecode_hash32: [u8; 32] = caller
             .read_memory(bytecode_hash32_offset, 32)?
             .try_into()
             .unwrap();
         let input = caller.read_memory(input_offset, input_len)?.to_vec();
-        let fuel_data = caller.read_memory(fuel_offset, 4)?;
-        let fuel = LittleEndian::read_u32(fuel_data);
-        let exit_code = match Self::fn_impl(
+        let fuel_limit = LittleEndian::read_u64(caller.read_memory(fuel_offset, 8)?);
+        // zero fuel limit disables fuel metering, it can't be used inside metered execution
+        if fuel_limit == 0 && caller.fuel_consumed().is_some() {
+            return Ok(ExitCode::OutOfFuel.into_i32());
+        }
+        // the callee can't consume more fuel than the caller has
+        let callee_fuel_limit = match remaining_fuel(&caller) {
+            Some(remaining_fuel) => fuel_limit.min(remaining_fuel),
+            None => fuel_limit,
+        };
+        let mut fuel = callee_fuel_limit;
+        let result = Self::fn_impl(
             caller.data_mut(),
             &bytecode_hash32,
             input,
             return_len,
-            fuel,
+            &mut fuel,
             state,
-        ) {
-            Ok((return_data, remaining_fuel)) => {
+        );
+        // fuel consumed by the callee is charged from the caller as well
+        let fuel_consumed = callee_fuel_limit - fuel;
+        consume_fuel(&mut caller, fuel_consumed)?;
+        // remaining fuel is returned relative to the requested limit, so the caller computes
+        // consumed fuel in the same way no matter whether the limit is clamped
+        let mut fuel_buffer = [0u8; 8];
+        LittleEndian::write_u64(&mut fuel_buffer, fuel_limit - fuel_consumed);
+        caller.write_memory(fuel_offset, &fuel_buffer)?;
+        let exit_code = match result {
+            Ok(return_data) => {
                 if return_len > 0 {
                     caller.write_memory(return_offset, &return_data)?;
                 }
-                let mut fuel_buffer = [0u8; 4];
-                LittleEndian::write_u32(&mut fuel_buffer, remaining_fuel);
-                caller.write_memory(fuel_offset, &fuel_buffer)?;
                 ExitCode::Ok.into_i32()
             }
             Err(err) => err,
@@ -45,36 +71,73 @@ impl SysExecHash {
         Ok(exit_code)
     }
 
+    /// Executes the bytecode by its hash, `fuel` is the fuel limit and it's replaced with the
+    /// remaining fuel after the execution (even if the execution fails)
     pub fn fn_impl<T>(
         ctx: &mut RuntimeContext<T>,
         bytecode_hash32: &[u8; 32],
         input: Vec<u8>,
         return_len: u32,
-        fuel_limit: u32,
-        _state: u32,
-    ) -> Result<(Vec<u8>, u32), i32> {
+        fuel: &mut u64,
+        state: u32,
+    ) -> Result<Vec<u8>, i32> {
+        let jzkt = ctx.jzkt.clone().unwrap();
+        // return data of the previous call must not leak if this one fails to start
+        ctx.return_data.clear();
+        jzkt.borrow_mut().trace_frame(JournalFrame::Enter {
+            bytecode_hash: Some(*bytecode_hash32),
+            input: input.clone(),
+            state,
+        });
+        let fuel_limit = *fuel;
+        let result = Self::exec(ctx, bytecode_hash32, input, return_len, fuel, state);
+        jzkt.borrow_mut().trace_frame(JournalFrame::Exit {
+            exit_code: result
+                .as_ref()
+                .err()
+                .copied()
+                .unwrap_or(ExitCode::Ok.into_i32()),
+            output: result.clone().unwrap_or_else(|_| ctx.return_data.clone()),
+            fuel_consumed: fuel_limit - *fuel,
+        });
+        result
+    }
+
+    fn exec<T>(
+        ctx: &mut RuntimeContext<T>,
+        bytecode_hash32: &[u8; 32],
+        input: Vec<u8>,
+        return_len: u32,
+        fuel: &mut u64,
+        state: u32,
+    ) -> Result<Vec<u8>, i32> {
         let import_linker = Runtime::<()>::new_sovereign_linker();
         let jzkt = ctx.jzkt.clone().unwrap();
         let bytecode_ptr_and_size = jzkt.borrow_mut().preimage_ptr_and_size(bytecode_hash32);
         let mut next_ctx = RuntimeContext::new(bytecode_ptr_and_size);
         next_ctx
             .with_input(input)
-            .with_state(STATE_MAIN)
+            .with_state(state & !STATE_STATIC_FLAG)
             .with_is_shared(false)
-            .with_fuel_limit(fuel_limit)
+            // static mode is inherited by all nested calls
+            .with_is_static(ctx.is_static || state & STATE_STATIC_FLAG != 0)
+            .with_fuel_limit(*fuel)
+            .with_fuel_schedule(ctx.fuel_schedule.clone())
             .with_jzkt(ctx.jzkt.clone().unwrap());
         let execution_result = Runtime::<()>::run_with_context(next_ctx, import_linker)
             .map_err(|_| ExitCode::TransactError.into_i32())?;
-        let fuel_consumed = execution_result.fuel_consumed().unwrap_or_default() as u32;
+        let fuel_consumed = execution_result.fuel_consumed().unwrap_or_default();
+        *fuel = fuel.saturating_sub(fuel_consumed);
+        ctx.consumed_fuel += fuel_consumed;
         let output = execution_result.data().output();
         if return_len > 0 && output.len() > return_len as usize {
             return Err(ExitCode::OutputOverflow.into_i32());
         }
+        // keep output of failed executions as well, because it contains revert data
+        ctx.return_data = output.clone();
         if execution_result.data().exit_code != ExitCode::Ok.into_i32() {
             return Err(execution_result.data().exit_code);
         }
-        ctx.consumed_fuel += fuel_consumed;
-        ctx.return_data = output.clone();
-        Ok((output.clone(), fuel_limit - fuel_consumed))
+        Ok(output.clone())
     }
 }
@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::ExitCode;
+use crate::{instruction::consume_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct SysForwardOutput;
@@ -10,6 +10,12 @@ impl SysForwardOutput {
         offset: u32,
         len: u32,
     ) -> Result<(), Trap> {
+        let fuel = caller
+            .data()
+            .fuel_schedule()
+            .sys_func_fuel(SysFuncIdx::SYS_FORWARD_OUTPUT)
+            .word_cost(len as u64);
+        consume_fuel(&mut caller, fuel)?;
         Self::fn_impl(&mut caller.data_mut(), offset, len).map_err(|err| err.into_trap())?;
         Ok(())
     }
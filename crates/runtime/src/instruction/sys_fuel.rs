@@ -0,0 +1,15 @@
+use crate::{instruction::consume_fuel, RuntimeContext};
+use rwasm::{core::Trap, Caller};
+
+pub struct SysFuel;
+
+impl SysFuel {
+    /// Charges additional fuel, system contracts use it to meter work that isn't done by rWASM
+    /// instructions (e.g. translation of WASM binaries)
+    pub fn fn_handler<T>(
+        mut caller: Caller<'_, RuntimeContext<T>>,
+        delta: u64,
+    ) -> Result<(), Trap> {
+        consume_fuel(&mut caller, delta)
+    }
+}
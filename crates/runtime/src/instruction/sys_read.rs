@@ -1,5 +1,5 @@
-use crate::RuntimeContext;
-use fluentbase_types::ExitCode;
+use crate::{instruction::consume_fuel, RuntimeContext};
+use fluentbase_types::{ExitCode, SysFuncIdx};
 use rwasm::{core::Trap, Caller};
 
 pub struct SysRead;
@@ -11,6 +11,12 @@ impl SysRead {
         offset: u32,
         length: u32,
     ) -> Result<(), Trap> {
+        let fuel = caller
+            .data()
+            .fuel_schedule()
+            .sys_func_fuel(SysFuncIdx::SYS_READ)
+            .word_cost(length as u64);
+        consume_fuel(&mut caller, fuel)?;
         let input = Self::fn_impl(caller.data(), offset, length).map_err(|err| err.into_trap())?;
         let _ = caller.write_memory(target, &input)?;
         Ok(())
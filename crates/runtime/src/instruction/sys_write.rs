@@ -1,4 +1,5 @@
-use crate::RuntimeContext;
+use crate::{instruction::consume_fuel, RuntimeContext};
+use fluentbase_types::SysFuncIdx;
 use rwasm::{core::Trap, Caller};
 
 pub struct SysWrite;
@@ -9,6 +10,12 @@ impl SysWrite {
         offset: u32,
         length: u32,
     ) -> Result<(), Trap> {
+        let fuel = caller
+            .data()
+            .fuel_schedule()
+            .sys_func_fuel(SysFuncIdx::SYS_WRITE)
+            .word_cost(length as u64);
+        consume_fuel(&mut caller, fuel)?;
         let data = caller.read_memory(offset, length)?.to_vec();
         Self::fn_impl(caller.data_mut(), &data);
         Ok(())
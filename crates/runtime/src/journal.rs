@@ -3,7 +3,7 @@ use core::mem::take;
 use fluentbase_poseidon::{hash_with_domain, Poseidon};
 use fluentbase_types::{Address, Bytes, ExitCode, B256};
 use halo2curves::bn256::Fr;
-use hashbrown::HashMap;
+use hashbrown::{HashMap, HashSet};
 
 enum JournalEvent {
     ItemChanged {
@@ -45,7 +45,7 @@ impl JournalEvent {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct JournalCheckpoint(pub u32, pub u32);
 
 impl Into<(u32, u32)> for JournalCheckpoint {
@@ -72,10 +72,40 @@ impl JournalCheckpoint {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct JournalLog {
-    address: Address,
-    topics: Vec<B256>,
-    data: Bytes,
+    pub address: Address,
+    pub topics: Vec<B256>,
+    pub data: Bytes,
+}
+
+/// Boundary of the nested execution (`_sys_exec`/`_sys_exec_hash`), frames aren't reverted on
+/// rollback, so they can be used to trace failed executions as well
+#[derive(Debug, Clone, PartialEq)]
+pub enum JournalFrame {
+    Enter {
+        /// Hash of the executed bytecode (`None` if the bytecode is passed by value)
+        bytecode_hash: Option<[u8; 32]>,
+        input: Vec<u8>,
+        state: u32,
+    },
+    Exit {
+        exit_code: i32,
+        output: Vec<u8>,
+        fuel_consumed: u64,
+    },
+}
+
+/// Change of the trie item made after some checkpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalChange {
+    pub key: [u8; 32],
+    /// Address and slot for storage items (only if it's stored or loaded using `store`/`load`)
+    pub storage_slot: Option<(Address, [u8; 32])>,
+    /// Value of the item before the checkpoint (`None` if item didn't exist)
+    pub prev_value: Option<Vec<[u8; 32]>>,
+    /// Current value of the item (`None` if item is removed)
+    pub value: Option<Vec<[u8; 32]>>,
 }
 
 pub trait IJournaledTrie {
@@ -88,6 +118,10 @@ pub trait IJournaledTrie {
     fn compute_root(&self) -> [u8; 32];
     fn emit_log(&mut self, address: Address, topics: Vec<B256>, data: Bytes);
     fn events(&self) -> &Vec<JournalEvent>;
+    fn changes(&self, checkpoint: JournalCheckpoint) -> Vec<JournalChange>;
+    fn logs(&self, checkpoint: JournalCheckpoint) -> &[JournalLog];
+    fn trace_frame(&mut self, frame: JournalFrame);
+    fn frames(&self) -> &[JournalFrame];
     fn commit(&mut self) -> Result<([u8; 32], Vec<JournalLog>), ExitCode>;
     fn rollback(&mut self, checkpoint: JournalCheckpoint);
     fn update_preimage(&mut self, key: &[u8; 32], field: u32, preimage: &[u8]) -> bool;
@@ -115,7 +149,9 @@ pub struct JournaledTrie<DB: TrieStorage> {
     storage: DB,
     state: HashMap<[u8; 32], usize>,
     preimages: HashMap<[u8; 32], Vec<u8>>,
+    storage_slots: HashMap<[u8; 32], (Address, [u8; 32])>,
     logs: Vec<JournalLog>,
+    frames: Vec<JournalFrame>,
     journal: Vec<JournalEvent>,
     root: [u8; 32],
     committed: usize,
@@ -130,7 +166,9 @@ impl<DB: TrieStorage> JournaledTrie<DB> {
             storage,
             state: HashMap::new(),
             preimages: HashMap::new(),
+            storage_slots: HashMap::new(),
             logs: Vec::new(),
+            frames: Vec::new(),
             journal: Vec::new(),
             root,
             committed: 0,
@@ -200,11 +238,13 @@ impl<DB: TrieStorage> IJournaledTrie for JournaledTrie<DB> {
 
     fn store(&mut self, address: &Address, slot: &[u8; 32], value: &[u8; 32]) {
         let storage_key = Self::storage_key(address, slot);
+        self.storage_slots.insert(storage_key, (*address, *slot));
         self.update(&storage_key, &vec![*value], 1);
     }
 
     fn load(&mut self, address: &Address, slot: &[u8; 32]) -> Option<([u8; 32], bool)> {
         let storage_key = Self::storage_key(address, slot);
+        self.storage_slots.insert(storage_key, (*address, *slot));
         let (values, _flags, is_cold) = self.get(&storage_key)?;
         assert_eq!(
             values.len(),
@@ -239,6 +279,49 @@ impl<DB: TrieStorage> IJournaledTrie for JournaledTrie<DB> {
         return &self.journal;
     }
 
+    fn changes(&self, checkpoint: JournalCheckpoint) -> Vec<JournalChange> {
+        let mut changes = Vec::new();
+        let mut visited = HashSet::new();
+        for event in self.journal.iter().skip(checkpoint.state()) {
+            let key = event.key();
+            if !visited.insert(*key) {
+                continue;
+            }
+            // first change after the checkpoint refers to the value before the checkpoint
+            let prev_value = match event.prev_state() {
+                Some(prev_state) => self.journal[prev_state].preimage().map(|(v, _)| v),
+                None => self.storage.get(key).map(|(v, _)| v),
+            };
+            let value = self
+                .state
+                .get(key)
+                .and_then(|index| self.journal[*index].preimage())
+                .map(|(v, _)| v);
+            if prev_value == value {
+                continue;
+            }
+            changes.push(JournalChange {
+                key: *key,
+                storage_slot: self.storage_slots.get(key).copied(),
+                prev_value,
+                value,
+            });
+        }
+        changes
+    }
+
+    fn logs(&self, checkpoint: JournalCheckpoint) -> &[JournalLog] {
+        &self.logs[checkpoint.logs().min(self.logs.len())..]
+    }
+
+    fn trace_frame(&mut self, frame: JournalFrame) {
+        self.frames.push(frame);
+    }
+
+    fn frames(&self) -> &[JournalFrame] {
+        &self.frames
+    }
+
     fn commit(&mut self) -> Result<([u8; 32], Vec<JournalLog>), ExitCode> {
         if self.committed >= self.journal.len() {
             panic!("nothing to commit")
@@ -266,7 +349,9 @@ impl<DB: TrieStorage> IJournaledTrie for JournaledTrie<DB> {
         }
         self.journal.clear();
         self.preimages.clear();
+        self.storage_slots.clear();
         self.state.clear();
+        self.frames.clear();
         let logs = take(&mut self.logs);
         self.committed = 0;
         self.root = self.storage.compute_root();
@@ -350,13 +435,14 @@ impl<DB: TrieStorage> IJournaledTrie for JournaledTrie<DB> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        journal::{IJournaledTrie, JournaledTrie},
+        journal::{IJournaledTrie, JournalFrame, JournaledTrie},
         types::InMemoryTrieDb,
         zktrie::ZkTrieStateDb,
-        JournalCheckpoint, TrieStorage,
+        JournalCheckpoint,
+        TrieStorage,
     };
     use fluentbase_poseidon::poseidon_hash;
-    use fluentbase_types::address;
+    use fluentbase_types::{address, Bytes};
 
     fn calc_trie_root(values: Vec<([u8; 32], Vec<[u8; 32]>, u32)>) -> [u8; 32] {
         let db = InMemoryTrieDb::default();
@@ -518,4 +604,59 @@ mod tests {
         // value is cold because we committed state before that made it empty
         assert_eq!(is_cold, true);
     }
+
+    #[test]
+    fn test_changes_and_logs() {
+        let db = InMemoryTrieDb::default();
+        let zktrie = ZkTrieStateDb::new_empty(db);
+        let mut journal = JournaledTrie::new(zktrie);
+        let address = address!("0000000000000000000000000000000000000001");
+        journal.update(&bytes32!("key1"), &vec![bytes32!("val1")], 0);
+        journal.commit().unwrap();
+        let checkpoint = journal.checkpoint();
+        journal.update(&bytes32!("key1"), &vec![bytes32!("val2")], 0);
+        journal.update(&bytes32!("key1"), &vec![bytes32!("val3")], 0);
+        journal.store(&address, &bytes32!("slot1"), &bytes32!("value1"));
+        journal.emit_log(address, vec![], Bytes::from_static(&[1, 2, 3]));
+        // key is changed twice, but only the last value matters
+        let changes = journal.changes(checkpoint);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].key, bytes32!("key1"));
+        assert_eq!(changes[0].storage_slot, None);
+        assert_eq!(changes[0].prev_value, Some(vec![bytes32!("val1")]));
+        assert_eq!(changes[0].value, Some(vec![bytes32!("val3")]));
+        assert_eq!(changes[1].storage_slot, Some((address, bytes32!("slot1"))));
+        assert_eq!(changes[1].prev_value, None);
+        assert_eq!(changes[1].value, Some(vec![bytes32!("value1")]));
+        assert_eq!(journal.logs(checkpoint).len(), 1);
+        // changes that are reverted back don't appear in the diff
+        let checkpoint = journal.checkpoint();
+        journal.update(&bytes32!("key2"), &vec![bytes32!("val2")], 0);
+        journal.rollback(checkpoint);
+        assert!(journal.changes(checkpoint).is_empty());
+    }
+
+    #[test]
+    fn test_frames_are_not_reverted() {
+        let db = InMemoryTrieDb::default();
+        let zktrie = ZkTrieStateDb::new_empty(db);
+        let mut journal = JournaledTrie::new(zktrie);
+        let checkpoint = journal.checkpoint();
+        journal.trace_frame(JournalFrame::Enter {
+            bytecode_hash: None,
+            input: vec![],
+            state: 0,
+        });
+        journal.update(&bytes32!("key1"), &vec![bytes32!("val1")], 0);
+        journal.trace_frame(JournalFrame::Exit {
+            exit_code: -1,
+            output: vec![],
+            fuel_consumed: 10,
+        });
+        journal.rollback(checkpoint);
+        assert_eq!(journal.frames().len(), 2);
+        journal.update(&bytes32!("key1"), &vec![bytes32!("val1")], 0);
+        journal.commit().unwrap();
+        assert!(journal.frames().is_empty());
+    }
 }
@@ -110,7 +110,9 @@ macro_rules! impl_runtime_handler {
                 use rwasm::AsContextMut;
                 let func = rwasm::Func::wrap(
                     store.as_context_mut(),
-                    |caller: Caller<'_, RuntimeContext<'t, T>>, $($t)*| -> Result<$out, rwasm::core::Trap> {
+                    |mut caller: Caller<'_, RuntimeContext<'t, T>>, $($t)*| -> Result<$out, rwasm::core::Trap> {
+                        let fuel = caller.data().fuel_schedule().sys_func_fuel(Self::FUNC_INDEX).base;
+                        $crate::instruction::consume_fuel(&mut caller, fuel)?;
                         return $crate::forward_call_args! { Self::fn_handler, caller, [$($t)*] };
                     });
                 linker.engine().register_trampoline(Self::FUNC_INDEX as u32, func);
@@ -3,7 +3,12 @@ use crate::{
     journal::IJournaledTrie,
     types::{BytecodeRepr, RuntimeError},
 };
-use fluentbase_types::{create_shared_import_linker, create_sovereign_import_linker, ExitCode};
+use fluentbase_types::{
+    create_shared_import_linker,
+    create_sovereign_import_linker,
+    ExitCode,
+    FuelSchedule,
+};
 use rwasm::{
     core::ImportLinker,
     engine::Tracer,
@@ -24,15 +29,17 @@ pub struct RuntimeContext<'t, T> {
     pub context: Option<&'t mut T>,
     // context inputs
     pub(crate) bytecode: BytecodeRepr,
-    pub(crate) fuel_limit: u32,
+    pub(crate) fuel_limit: u64,
+    pub(crate) fuel_schedule: Rc<FuelSchedule>,
     pub(crate) state: u32,
     pub(crate) is_shared: bool,
+    pub(crate) is_static: bool,
     pub(crate) catch_trap: bool,
     pub(crate) input: Vec<u8>,
     // context outputs
     pub(crate) exit_code: i32,
     pub(crate) output: Vec<u8>,
-    pub(crate) consumed_fuel: u32,
+    pub(crate) consumed_fuel: u64,
     pub(crate) return_data: Vec<u8>,
     // storage
     pub(crate) jzkt: Option<Rc<RefCell<dyn IJournaledTrie>>>,
@@ -44,8 +51,10 @@ impl<'ctx, CTX> Clone for RuntimeContext<'ctx, CTX> {
             context: None,
             bytecode: self.bytecode.clone(),
             fuel_limit: self.fuel_limit.clone(),
+            fuel_schedule: self.fuel_schedule.clone(),
             state: self.state.clone(),
             is_shared: self.is_shared.clone(),
+            is_static: self.is_static.clone(),
             catch_trap: self.catch_trap.clone(),
             input: self.input.clone(),
             exit_code: self.exit_code.clone(),
@@ -63,8 +72,10 @@ impl<'t, T> Default for RuntimeContext<'t, T> {
             context: None,
             bytecode: Default::default(),
             fuel_limit: 0,
+            fuel_schedule: Default::default(),
             state: 0,
             is_shared: false,
+            is_static: false,
             catch_trap: true,
             input: vec![],
             exit_code: 0,
@@ -104,16 +115,32 @@ impl<'t, T> RuntimeContext<'t, T> {
         self
     }
 
+    /// Enables static mode, state modifications are forbidden in this mode
+    pub fn with_is_static(&mut self, is_static: bool) -> &mut Self {
+        self.is_static = is_static;
+        self
+    }
+
     pub fn with_catch_trap(&mut self, catch_trap: bool) -> &mut Self {
         self.catch_trap = catch_trap;
         self
     }
 
-    pub fn with_fuel_limit(&mut self, fuel_limit: u32) -> &mut Self {
+    pub fn with_fuel_limit(&mut self, fuel_limit: u64) -> &mut Self {
         self.fuel_limit = fuel_limit;
         self
     }
 
+    /// Sets fuel costs of host functions, the schedule is inherited by nested executions
+    pub fn with_fuel_schedule(&mut self, fuel_schedule: Rc<FuelSchedule>) -> &mut Self {
+        self.fuel_schedule = fuel_schedule;
+        self
+    }
+
+    pub fn fuel_schedule(&self) -> &FuelSchedule {
+        &self.fuel_schedule
+    }
+
     pub fn with_jzkt(&mut self, jzkt: Rc<RefCell<dyn IJournaledTrie>>) -> &mut Self {
         self.jzkt = Some(jzkt);
         self
@@ -160,6 +187,10 @@ impl<'t, T> RuntimeContext<'t, T> {
         self.state
     }
 
+    pub fn is_static(&self) -> bool {
+        self.is_static
+    }
+
     pub fn clean_output(&mut self) {
         self.output = vec![];
     }
@@ -280,7 +311,7 @@ impl<'t, T> Runtime<'t, T> {
         let mut store = Store::<RuntimeContext<T>>::new(&engine, runtime_context);
 
         if fuel_limit > 0 {
-            store.add_fuel(fuel_limit as u64).unwrap();
+            store.add_fuel(fuel_limit).unwrap();
         }
 
         let result = Self {
@@ -1,6 +1,14 @@
-use crate::{runtime::Runtime, RuntimeContext};
+use crate::{
+    journal::JournaledTrie,
+    runtime::Runtime,
+    types::InMemoryTrieDb,
+    zktrie::ZkTrieStateDb,
+    RuntimeContext,
+};
+use fluentbase_types::{ExitCode, FuelSchedule, SysFuncCost, SysFuncIdx};
 use hex_literal::hex;
 use rwasm::rwasm::{BinaryFormat, RwasmModule};
+use std::{cell::RefCell, rc::Rc};
 
 pub(crate) fn wat2rwasm(wat: &str) -> Vec<u8> {
     let import_linker = Runtime::<()>::new_sovereign_linker();
@@ -109,3 +117,179 @@ fn test_keccak256() {
         execution_result.data().output.as_slice()
     );
 }
+
+#[test]
+fn test_host_function_fuel() {
+    let keccak256 = |data_len: u32, fuel_limit: u64, fuel_schedule: FuelSchedule| {
+        let rwasm_binary = wat2rwasm(&format!(
+            r#"
+(module
+  (type (;0;) (func (param i32 i32 i32)))
+  (type (;1;) (func))
+  (import "fluentbase_v1alpha" "_crypto_keccak256" (func $_evm_keccak256 (type 0)))
+  (func $main (type 1)
+    i32.const 0
+    i32.const {data_len}
+    i32.const 0
+    call $_evm_keccak256
+    )
+  (memory (;0;) 1)
+  (export "main" (func $main)))
+    "#
+        ));
+        let mut ctx = RuntimeContext::new(rwasm_binary);
+        ctx.with_fuel_limit(fuel_limit)
+            .with_fuel_schedule(Rc::new(fuel_schedule));
+        let import_linker = Runtime::<()>::new_sovereign_linker();
+        let execution_result = Runtime::<()>::run_with_context(ctx, import_linker).unwrap();
+        (
+            execution_result.data().exit_code,
+            execution_result.fuel_consumed().unwrap_or_default(),
+        )
+    };
+    // hashing of the bigger data costs more (the same instructions are executed)
+    let (exit_code, small_fuel) = keccak256(32, 1_000_000, FuelSchedule::default());
+    assert_eq!(exit_code, 0);
+    let (exit_code, big_fuel) = keccak256(3200, 1_000_000, FuelSchedule::default());
+    assert_eq!(exit_code, 0);
+    let cost = FuelSchedule::default().sys_func_fuel(SysFuncIdx::CRYPTO_KECCAK256);
+    assert_eq!(
+        big_fuel - small_fuel,
+        cost.word_cost(3200) - cost.word_cost(32)
+    );
+    // host function can't be executed if there is not enough fuel
+    let (exit_code, _) = keccak256(32000, cost.word_cost(32000) / 2, FuelSchedule::default());
+    assert_eq!(exit_code, ExitCode::OutOfFuel.into_i32());
+    // costs of host functions are taken from the schedule
+    let fuel_schedule = FuelSchedule::default()
+        .with_sys_func_gas_cost(SysFuncIdx::CRYPTO_KECCAK256, SysFuncCost::new(30, 60));
+    let (exit_code, custom_fuel) = keccak256(3200, 1_000_000, fuel_schedule.clone());
+    assert_eq!(exit_code, 0);
+    assert_eq!(
+        custom_fuel - big_fuel,
+        fuel_schedule
+            .sys_func_fuel(SysFuncIdx::CRYPTO_KECCAK256)
+            .word_cost(3200)
+            - cost.word_cost(3200)
+    );
+}
+
+#[test]
+fn test_sys_exec_fuel_limit_is_clamped() {
+    // infinite loop that consumes all fuel it gets
+    let callee = wat2rwasm(
+        r#"
+(module
+  (func $main
+    (loop $continue (br $continue))
+    )
+  (export "main" (func $main)))
+    "#,
+    );
+    let callee_hex = callee
+        .iter()
+        .map(|b| format!("\\{b:02x}"))
+        .collect::<String>();
+    // callee requests the maximum fuel limit, it's stored at the offset 0
+    let caller = wat2rwasm(&format!(
+        r#"
+(module
+  (type (;0;) (func (param i32 i32 i32 i32 i32 i32 i32 i32) (result i32)))
+  (type (;1;) (func))
+  (import "fluentbase_v1alpha" "_sys_exec" (func $_sys_exec (type 0)))
+  (func $main (type 1)
+    i32.const 8
+    i32.const {callee_len}
+    i32.const 0
+    i32.const 0
+    i32.const 0
+    i32.const 0
+    i32.const 0
+    i32.const 0
+    call $_sys_exec
+    drop
+    )
+  (memory (;0;) 1)
+  (data (;0;) (i32.const 0) "\ff\ff\ff\ff\ff\ff\ff\ff")
+  (data (;1;) (i32.const 8) "{callee_hex}")
+  (export "main" (func $main)))
+    "#,
+        callee_len = callee.len(),
+    ));
+    let jzkt = Rc::new(RefCell::new(JournaledTrie::new(ZkTrieStateDb::new_empty(
+        InMemoryTrieDb::default(),
+    ))));
+    let mut ctx = RuntimeContext::new(caller);
+    ctx.with_fuel_limit(100_000).with_jzkt(jzkt);
+    let import_linker = Runtime::<()>::new_sovereign_linker();
+    let execution_result = Runtime::<()>::run_with_context(ctx, import_linker).unwrap();
+    // the callee runs out of the caller's fuel instead of running with the requested limit
+    assert_eq!(
+        execution_result.data().exit_code,
+        ExitCode::OutOfFuel.into_i32()
+    );
+    assert!(execution_result.fuel_consumed().unwrap_or_default() <= 100_000);
+}
+
+#[test]
+fn test_sys_fuel() {
+    let charge_fuel = |fuel_limit: u64| {
+        let rwasm_binary = wat2rwasm(
+            r#"
+(module
+  (type (;0;) (func (param i64)))
+  (type (;1;) (func))
+  (import "fluentbase_v1alpha" "_sys_fuel" (func $_sys_fuel (type 0)))
+  (func $main (type 1)
+    i64.const 1000
+    call $_sys_fuel
+    )
+  (export "main" (func $main)))
+    "#,
+        );
+        let mut ctx = RuntimeContext::new(rwasm_binary);
+        ctx.with_fuel_limit(fuel_limit);
+        let import_linker = Runtime::<()>::new_sovereign_linker();
+        let execution_result = Runtime::<()>::run_with_context(ctx, import_linker).unwrap();
+        (
+            execution_result.data().exit_code,
+            execution_result.fuel_consumed().unwrap_or_default(),
+        )
+    };
+    let (exit_code, fuel_consumed) = charge_fuel(100_000);
+    assert_eq!(exit_code, 0);
+    assert!(fuel_consumed > 1000);
+    let (exit_code, _) = charge_fuel(500);
+    assert_eq!(exit_code, ExitCode::OutOfFuel.into_i32());
+}
+
+#[test]
+fn test_static_write_protection() {
+    let emit_log = || {
+        let rwasm_binary = wat2rwasm(
+            r#"
+(module
+  (type (;0;) (func (param i32 i32 i32 i32 i32)))
+  (type (;1;) (func))
+  (import "fluentbase_v1alpha" "_jzkt_emit_log" (func $_jzkt_emit_log (type 0)))
+  (func $main (type 1)
+    i32.const 0
+    i32.const 0
+    i32.const 0
+    i32.const 0
+    i32.const 0
+    call $_jzkt_emit_log
+    )
+  (memory (;0;) 1)
+  (export "main" (func $main)))
+    "#,
+        );
+        let mut ctx = RuntimeContext::new(rwasm_binary);
+        ctx.with_is_static(true);
+        let import_linker = Runtime::<()>::new_sovereign_linker();
+        let execution_result = Runtime::<()>::run_with_context(ctx, import_linker).unwrap();
+        execution_result.data().exit_code
+    };
+    // state modifications are forbidden in static mode
+    assert_eq!(emit_log(), ExitCode::WriteProtection.into_i32());
+}
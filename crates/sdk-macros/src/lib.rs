@@ -0,0 +1,399 @@
+extern crate proc_macro;
+
+use crypto_hashes::sha3::{Digest, Keccak256};
+use proc_macro::TokenStream;
+use quote::ToTokens;
+use syn::{
+    Data,
+    DeriveInput,
+    Expr,
+    Fields,
+    FnArg,
+    GenericArgument,
+    ImplItem,
+    ItemImpl,
+    Lit,
+    Pat,
+    PathArguments,
+    ReturnType,
+    Type,
+    Visibility,
+};
+
+/// Generates `deploy`/`main` entrypoints for the contract's impl block.
+///
+/// Every public method (except `deploy`) becomes callable using Solidity-compatible 4-byte
+/// selector, where the selector is computed from the camel-cased method name and Solidity names of
+/// the argument types (ex.: `balance_of(&self, owner: Address)` becomes `balanceOf(address)`).
+/// Arguments that follow the selector and returned values are encoded with `fluentbase-codec`.
+/// If the method returns `Err` then execution is reverted with Solidity's `Error(string)` reason.
+///
+/// Pass `#[contract(export)]` to also emit `#[no_mangle]` wasm exports, otherwise the generated
+/// `deploy` and `main` functions can be forwarded manually.
+#[proc_macro_attribute]
+pub fn contract(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let with_exports = match attr.to_string().trim() {
+        "" => false,
+        "export" => true,
+        v => panic!("unsupported contract attribute '{}'", v),
+    };
+    let impl_ast = syn::parse::<ItemImpl>(item.clone()).unwrap();
+    assert!(
+        impl_ast.trait_.is_none(),
+        "contract macro must be applied to an inherent impl block"
+    );
+    let type_ident = impl_ast.self_ty.to_token_stream().to_string();
+
+    let mut const_decls = String::new();
+    let mut match_arms = String::new();
+    let mut deploy_call = String::new();
+
+    for impl_item in &impl_ast.items {
+        let fn_item = match impl_item {
+            ImplItem::Fn(fn_item) => fn_item,
+            _ => continue,
+        };
+        if !matches!(fn_item.vis, Visibility::Public(_)) {
+            continue;
+        }
+        let fn_ident = fn_item.sig.ident.to_string();
+        let mut has_receiver = false;
+        let mut arg_types = Vec::<&Type>::new();
+        for fn_arg in &fn_item.sig.inputs {
+            match fn_arg {
+                FnArg::Receiver(_) => has_receiver = true,
+                FnArg::Typed(pat_type) => {
+                    match pat_type.pat.as_ref() {
+                        Pat::Ident(_) | Pat::Wild(_) => {}
+                        _ => panic!("unsupported parameter ident type"),
+                    }
+                    arg_types.push(pat_type.ty.as_ref());
+                }
+            }
+        }
+        if !has_receiver {
+            continue;
+        }
+        if fn_ident == "deploy" {
+            assert!(arg_types.is_empty(), "deploy method can't have arguments");
+            deploy_call = "contract.deploy();".to_string();
+            continue;
+        }
+        // calc method selector
+        let signature = format!(
+            "{}({})",
+            camel_case(&fn_ident),
+            arg_types
+                .iter()
+                .map(|ty| sol_type(ty))
+                .collect::<Vec<String>>()
+                .join(",")
+        );
+        let mut h = Keccak256::default();
+        h.update(signature.as_bytes());
+        let mut selector = [0u8; 4];
+        selector.copy_from_slice(&h.finalize()[0..4]);
+        let const_ident = format!("{}_SELECTOR", fn_ident.to_uppercase());
+        const_decls.push_str(
+            format!(
+                "/// Selector of `{signature}`\npub const {const_ident}: [u8; 4] = {selector:?};\n"
+            )
+            .as_str(),
+        );
+        // decode input arguments
+        let mut decode_args = String::new();
+        let mut arg_names = Vec::<String>::new();
+        let mut field_offset = "0".to_string();
+        for (i, arg_type) in arg_types.iter().enumerate() {
+            let arg_type = arg_type.to_token_stream().to_string();
+            let arg_name = format!("arg{}", i);
+            decode_args.push_str(
+                r#"
+                let mut #ARG#: #TYPE# = ::core::default::Default::default();
+                <#TYPE# as ::fluentbase_sdk::codec::Encoder<#TYPE#>>::decode_body(
+                    &mut decoder,
+                    #OFFSET#,
+                    &mut #ARG#,
+                );
+                "#
+                .replace("#ARG#", &arg_name)
+                .replace("#TYPE#", &arg_type)
+                .replace("#OFFSET#", &field_offset)
+                .as_str(),
+            );
+            field_offset = format!(
+                "{} + <{} as ::fluentbase_sdk::codec::Encoder<{}>>::HEADER_SIZE",
+                field_offset, arg_type, arg_type
+            );
+            arg_names.push(arg_name);
+        }
+        let call = format!("contract.{}({})", fn_ident, arg_names.join(", "));
+        let handle_result = match &fn_item.sig.output {
+            ReturnType::Default => format!("{};", call),
+            ReturnType::Type(_, ty) => match result_ok_type(ty) {
+                Some(ok_type) if is_unit(ok_type) => format!(
+                    "if let Err(err) = {} {{ ::fluentbase_sdk::contract::revert(err); }}",
+                    call
+                ),
+                Some(_) => format!(
+                    "match {} {{
+                        Ok(result) => ::fluentbase_sdk::contract::write_output(&result),
+                        Err(err) => ::fluentbase_sdk::contract::revert(err),
+                    }}",
+                    call
+                ),
+                None if is_unit(ty) => format!("{};", call),
+                None => format!(
+                    "let result = {};\n::fluentbase_sdk::contract::write_output(&result);",
+                    call
+                ),
+            },
+        };
+        match_arms.push_str(
+            r#"
+            #TYPE_IDENT#::#CONST_IDENT# => {
+                let mut decoder = ::fluentbase_sdk::codec::BufferDecoder::new(&input[4..]);
+                #DECODE_ARGS#
+                #HANDLE_RESULT#
+            }
+            "#
+            .replace("#CONST_IDENT#", &const_ident)
+            .replace("#DECODE_ARGS#", &decode_args)
+            .replace("#HANDLE_RESULT#", &handle_result)
+            .as_str(),
+        );
+    }
+
+    let mut builder = String::new();
+    builder.push_str(
+        r#"
+        __ITEM__
+        impl __TYPE_IDENT__ {
+            __CONST_DECLS__
+        }
+        #[allow(unused_mut, unused_variables)]
+        pub fn deploy() {
+            let mut contract = <__TYPE_IDENT__ as ::core::default::Default>::default();
+            __DEPLOY_CALL__
+        }
+        #[allow(unused_mut, unused_variables)]
+        pub fn main() {
+            let input = ::fluentbase_sdk::evm::ExecutionContext::contract_input();
+            if input.len() < 4 {
+                ::fluentbase_sdk::contract::revert("missing method selector");
+                return;
+            }
+            let mut selector = [0u8; 4];
+            selector.copy_from_slice(&input[0..4]);
+            let mut contract = <__TYPE_IDENT__ as ::core::default::Default>::default();
+            match selector {
+                __MATCH_ARMS__
+                _ => ::fluentbase_sdk::contract::revert("unknown method"),
+            }
+        }
+    "#,
+    );
+    if with_exports {
+        builder.push_str(
+            r#"
+            #[cfg(target_arch = "wasm32")]
+            mod __contract_exports {
+                #[no_mangle]
+                pub extern "C" fn deploy() {
+                    super::deploy()
+                }
+                #[no_mangle]
+                pub extern "C" fn main() {
+                    super::main()
+                }
+            }
+        "#,
+        );
+    }
+    builder
+        .replace("__CONST_DECLS__", &const_decls)
+        .replace("__MATCH_ARMS__", &match_arms)
+        .replace("__DEPLOY_CALL__", &deploy_call)
+        .replace("#TYPE_IDENT#", &type_ident)
+        .replace("__TYPE_IDENT__", &type_ident)
+        .replace("__ITEM__", &item.to_string())
+        .parse()
+        .unwrap()
+}
+
+/// Implements `fluentbase_sdk::event::Event` for the struct with named fields.
+///
+/// The signature topic is `keccak256("Name(type1,type2,...)")`, fields marked with `#[indexed]`
+/// become topics and the rest of the fields are ABI-encoded into log's data. Mark the struct with
+/// `#[anonymous]` to skip the signature topic.
+#[proc_macro_derive(Event, attributes(indexed, anonymous))]
+pub fn derive_event(item: TokenStream) -> TokenStream {
+    let derive_ast = syn::parse::<DeriveInput>(item).unwrap();
+    let struct_ident = derive_ast.ident.to_string();
+    let is_anonymous = derive_ast
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident("anonymous"));
+    let fields = match &derive_ast.data {
+        Data::Struct(data_struct) => match &data_struct.fields {
+            Fields::Named(fields) => fields.named.iter().collect::<Vec<_>>(),
+            Fields::Unit => vec![],
+            _ => panic!("event must have named fields"),
+        },
+        _ => panic!("event can only be derived for structs"),
+    };
+
+    let mut field_types = Vec::<String>::new();
+    let mut visit_fields = String::new();
+    let mut indexed_count = 0;
+    for field in &fields {
+        let is_indexed = field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("indexed"));
+        if is_indexed {
+            indexed_count += 1;
+        }
+        field_types.push(sol_type(&field.ty));
+        visit_fields.push_str(
+            format!(
+                "visitor(&self.{}, {});\n",
+                field.ident.as_ref().unwrap(),
+                is_indexed
+            )
+            .as_str(),
+        );
+    }
+    let max_indexed = if is_anonymous { 4 } else { 3 };
+    assert!(
+        indexed_count <= max_indexed,
+        "event can't have more than {} indexed fields",
+        max_indexed
+    );
+
+    let signature = format!("{}({})", struct_ident, field_types.join(","));
+    let mut h = Keccak256::default();
+    h.update(signature.as_bytes());
+    let mut signature_hash = [0u8; 32];
+    signature_hash.copy_from_slice(&h.finalize()[..]);
+
+    r#"
+        impl ::fluentbase_sdk::event::Event for __STRUCT_IDENT__ {
+            const SIGNATURE: &'static str = "__SIGNATURE__";
+            const SIGNATURE_HASH: [u8; 32] = __SIGNATURE_HASH__;
+            const ANONYMOUS: bool = __ANONYMOUS__;
+
+            fn visit_fields<'a>(
+                &'a self,
+                visitor: &mut dyn FnMut(&'a dyn ::fluentbase_sdk::event::AbiType, bool),
+            ) {
+                __VISIT_FIELDS__
+            }
+        }
+    "#
+    .replace("__STRUCT_IDENT__", &struct_ident)
+    .replace("__SIGNATURE__", &signature)
+    .replace("__SIGNATURE_HASH__", &format!("{:?}", signature_hash))
+    .replace("__ANONYMOUS__", &is_anonymous.to_string())
+    .replace("__VISIT_FIELDS__", &visit_fields)
+    .parse()
+    .unwrap()
+}
+
+fn camel_case(ident: &str) -> String {
+    let mut do_upper = false;
+    ident
+        .trim_start_matches("_")
+        .chars()
+        .filter_map(|v| {
+            if v == '_' {
+                do_upper = true;
+                None
+            } else if do_upper {
+                do_upper = false;
+                Some(v.to_ascii_uppercase())
+            } else {
+                Some(v)
+            }
+        })
+        .collect()
+}
+
+fn is_unit(ty: &Type) -> bool {
+    matches!(ty, Type::Tuple(tuple) if tuple.elems.is_empty())
+}
+
+fn generic_types(arguments: &PathArguments) -> Vec<&Type> {
+    match arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => vec![],
+    }
+}
+
+fn result_ok_type(ty: &Type) -> Option<&Type> {
+    let type_path = match ty {
+        Type::Path(type_path) => type_path,
+        _ => return None,
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Result" {
+        return None;
+    }
+    generic_types(&segment.arguments).first().copied()
+}
+
+fn array_len(expr: &Expr) -> usize {
+    match expr {
+        Expr::Lit(expr_lit) => match &expr_lit.lit {
+            Lit::Int(v) => v.base10_parse().unwrap(),
+            _ => panic!("unsupported array length"),
+        },
+        _ => panic!("array length must be a literal"),
+    }
+}
+
+/// Converts Rust type into Solidity's type name that is used in method signature
+fn sol_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let segment = type_path.path.segments.last().unwrap();
+            let ident = segment.ident.to_string();
+            match ident.as_str() {
+                "bool" => "bool".to_string(),
+                "u8" | "u16" | "u32" | "u64" | "u128" => format!("uint{}", &ident[1..]),
+                "i8" | "i16" | "i32" | "i64" | "i128" => format!("int{}", &ident[1..]),
+                "U256" => "uint256".to_string(),
+                "I256" => "int256".to_string(),
+                "Address" => "address".to_string(),
+                "B256" | "Bytes32" => "bytes32".to_string(),
+                "Bytes20" => "bytes20".to_string(),
+                "Bytes" => "bytes".to_string(),
+                "String" => "string".to_string(),
+                "Vec" => {
+                    let inner = generic_types(&segment.arguments)[0];
+                    match sol_type(inner).as_str() {
+                        "uint8" => "bytes".to_string(),
+                        v => format!("{}[]", v),
+                    }
+                }
+                _ => panic!("unsupported argument type '{}'", ident),
+            }
+        }
+        Type::Array(type_array) => {
+            let len = array_len(&type_array.len);
+            match sol_type(&type_array.elem).as_str() {
+                "uint8" if len <= 32 => format!("bytes{}", len),
+                v => format!("{}[{}]", v, len),
+            }
+        }
+        Type::Paren(type_paren) => sol_type(&type_paren.elem),
+        _ => panic!("unsupported argument type '{}'", ty.to_token_stream()),
+    }
+}
@@ -28,6 +28,7 @@ extern "C" {
     pub fn _sys_read_output(target: *mut u8, offset: u32, length: u32);
     pub fn _sys_forward_output(offset: u32, len: u32);
     pub fn _sys_state() -> u32;
+    pub fn _sys_fuel(delta: u64);
     pub fn _sys_exec(
         code_offset: *const u8,
         code_len: u32,
@@ -35,7 +36,7 @@ extern "C" {
         input_len: u32,
         return_offset: *mut u8,
         return_len: u32,
-        fuel_offset: *const u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> i32;
     pub fn _sys_exec_hash(
@@ -44,7 +45,7 @@ extern "C" {
         input_len: u32,
         return_offset: *mut u8,
         return_len: u32,
-        fuel_offset: *const u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> i32;
 
@@ -75,6 +76,13 @@ extern "C" {
     );
     pub fn _jzkt_commit(root32_ptr: *mut u8);
     pub fn _jzkt_rollback(checkpoint: u64);
+    pub fn _jzkt_store(address32_ptr: *const u8, slot32_ptr: *const u8, value32_ptr: *const u8);
+    pub fn _jzkt_load(
+        address32_ptr: *const u8,
+        slot32_ptr: *const u8,
+        value32_ptr: *mut u8,
+    ) -> bool;
     pub fn _jzkt_preimage_size(hash32_ptr: *const u8) -> u32;
     pub fn _jzkt_preimage_copy(hash32_ptr: *const u8, preimage_ptr: *mut u8);
+    pub fn _jzkt_preimage_read(hash32_ptr: *const u8, target: *mut u8, offset: u32, length: u32);
 }
@@ -0,0 +1,725 @@
+use crate::{
+    evm::{ContractInput, ExecutionContext},
+    LowLevelAPI,
+    LowLevelSDK,
+};
+use alloc::{vec, vec::Vec};
+use core::fmt::{Display, Formatter};
+use fluentbase_codec::Encoder;
+use fluentbase_core_api::{
+    api::CoreInput,
+    bindings::{
+        EvmCallcodeMethodInput,
+        EvmCreate2MethodInput,
+        EvmCreateMethodInput,
+        EvmDelegatecallMethodInput,
+        WasmCreate2MethodInput,
+        WasmCreateMethodInput,
+        EVM_CALLCODE_METHOD_ID,
+        EVM_CREATE2_METHOD_ID,
+        EVM_CREATE_METHOD_ID,
+        EVM_DELEGATECALL_METHOD_ID,
+        WASM_CREATE2_METHOD_ID,
+        WASM_CREATE_METHOD_ID,
+    },
+};
+use fluentbase_types::{
+    Address,
+    Bytes,
+    ExitCode,
+    B256,
+    CALL_STACK_LIMIT,
+    ECL_CONTRACT_ADDRESS,
+    JZKT_ACCOUNT_BALANCE_FIELD,
+    JZKT_ACCOUNT_FIELDS_COUNT,
+    JZKT_ACCOUNT_RWASM_BYTECODE_HASH_FIELD,
+    JZKT_ACCOUNT_SOURCE_BYTECODE_HASH_FIELD,
+    JZKT_COMPRESSION_FLAGS,
+    POSEIDON_EMPTY,
+    STATE_MAIN,
+    STATE_STATIC_FLAG,
+    U256,
+    WASM_MAGIC,
+    WCL_CONTRACT_ADDRESS,
+};
+
+/// Error of the failed call or create
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallError {
+    /// Exit code returned by the callee
+    pub exit_code: i32,
+    /// Output of the callee (f.e. revert reason)
+    pub output: Bytes,
+}
+
+impl Display for CallError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "call failed, exit code: {}", self.exit_code)
+    }
+}
+
+/// Type of the call, it's the same as EVM's call opcodes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CallScheme {
+    Call,
+    StaticCall,
+    DelegateCall,
+    CallCode,
+}
+
+/// Call frame that is executed by [`dispatch_call`]
+#[derive(Debug, Clone)]
+pub struct CallFrame<'a> {
+    pub scheme: CallScheme,
+    /// Account which bytecode is executed
+    pub code_address: Address,
+    /// Context's address, it differs from the code address for DELEGATECALL and CALLCODE
+    pub address: Address,
+    pub caller: Address,
+    /// Value seen by the callee, it's transferred by CALL and CALLCODE only
+    pub value: U256,
+    pub input: &'a [u8],
+    pub gas_limit: u64,
+    pub is_static: bool,
+}
+
+/// Result of the frame executed by [`dispatch_call`] or [`dispatch_create`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameResult {
+    pub exit_code: i32,
+    /// Output of the callee, for successful creates it's an address of the new contract
+    pub output: Bytes,
+    /// Gas consumed by the frame, it never exceeds the gas limit
+    pub gas_used: u64,
+}
+
+impl FrameResult {
+    fn from_exit_code(exit_code: ExitCode) -> Self {
+        Self {
+            exit_code: exit_code.into_i32(),
+            output: Bytes::new(),
+            gas_used: 0,
+        }
+    }
+
+    fn into_result(self) -> Result<Bytes, CallError> {
+        if self.exit_code != ExitCode::Ok.into_i32() {
+            return Err(CallError {
+                exit_code: self.exit_code,
+                output: self.output,
+            });
+        }
+        Ok(self.output)
+    }
+}
+
+/// Calls contract at the address, value is forwarded to the callee.
+///
+/// The callee's rWASM bytecode is executed directly, for EVM contracts it's an EVM loader that
+/// forwards execution to ECL, so the same call works for both EVM and WASM callees.
+pub fn call(
+    address: Address,
+    value: U256,
+    input: &[u8],
+    gas_limit: u64,
+) -> Result<Bytes, CallError> {
+    dispatch_call(&CallFrame {
+        scheme: CallScheme::Call,
+        code_address: address,
+        address,
+        caller: ExecutionContext::contract_address(),
+        value,
+        input,
+        gas_limit,
+        is_static: ExecutionContext::contract_is_static(),
+    })
+    .into_result()
+}
+
+/// Calls contract at the address without state modifications allowed
+pub fn static_call(address: Address, input: &[u8], gas_limit: u64) -> Result<Bytes, CallError> {
+    dispatch_call(&CallFrame {
+        scheme: CallScheme::StaticCall,
+        code_address: address,
+        address,
+        caller: ExecutionContext::contract_address(),
+        value: U256::ZERO,
+        input,
+        gas_limit,
+        is_static: true,
+    })
+    .into_result()
+}
+
+/// Executes bytecode of the contract at the address within the current context, it means that
+/// address, caller and value stay the same (DELEGATECALL).
+pub fn delegate_call(address: Address, input: &[u8], gas_limit: u64) -> Result<Bytes, CallError> {
+    dispatch_call(&CallFrame {
+        scheme: CallScheme::DelegateCall,
+        code_address: address,
+        address: ExecutionContext::contract_address(),
+        caller: ExecutionContext::contract_caller(),
+        value: ExecutionContext::contract_value(),
+        input,
+        gas_limit,
+        is_static: ExecutionContext::contract_is_static(),
+    })
+    .into_result()
+}
+
+/// Executes bytecode of the contract at the address within the current contract's storage, the
+/// current contract becomes a caller and value is sent to itself (CALLCODE)
+pub fn call_code(
+    address: Address,
+    value: U256,
+    input: &[u8],
+    gas_limit: u64,
+) -> Result<Bytes, CallError> {
+    let contract_address = ExecutionContext::contract_address();
+    dispatch_call(&CallFrame {
+        scheme: CallScheme::CallCode,
+        code_address: address,
+        address: contract_address,
+        caller: contract_address,
+        value,
+        input,
+        gas_limit,
+        is_static: ExecutionContext::contract_is_static(),
+    })
+    .into_result()
+}
+
+/// Deploys new contract, the bytecode can be either EVM or WASM, returns address of the new
+/// contract
+pub fn create(value: U256, init_code: &[u8], gas_limit: u64) -> Result<Address, CallError> {
+    let caller = ExecutionContext::contract_address();
+    let output = dispatch_create(caller, value, init_code, None, gas_limit).into_result()?;
+    Ok(Address::from_slice(output.as_ref()))
+}
+
+/// Deploys new contract with the deterministic address that is derived from the salt
+pub fn create2(
+    value: U256,
+    init_code: &[u8],
+    salt: U256,
+    gas_limit: u64,
+) -> Result<Address, CallError> {
+    let caller = ExecutionContext::contract_address();
+    let output = dispatch_create(caller, value, init_code, Some(salt), gas_limit).into_result()?;
+    Ok(Address::from_slice(output.as_ref()))
+}
+
+/// Executes the call frame, it's shared by the SDK calls and the EVM interpreter's sub calls.
+///
+/// The callee's rWASM bytecode (the EVM loader for EVM accounts) is executed with a contract
+/// input built from the frame. DELEGATECALL and CALLCODE of EVM bytecode are forwarded to ECL
+/// because the EVM loader loads bytecode from the context's address.
+pub fn dispatch_call(frame: &CallFrame) -> FrameResult {
+    let transfers_value = matches!(frame.scheme, CallScheme::Call | CallScheme::CallCode);
+    // static calls can't pass any value
+    if frame.is_static && transfers_value && frame.value != U256::ZERO {
+        return FrameResult::from_exit_code(ExitCode::WriteProtection);
+    }
+    let is_evm_account = is_evm_account(&frame.code_address);
+    let (code_address, input) = match frame.scheme {
+        CallScheme::DelegateCall if is_evm_account => {
+            let method_data = EvmDelegatecallMethodInput {
+                callee_address20: frame.code_address.into_array(),
+                args: frame.input.to_vec(),
+                gas_limit: gas_limit32(frame.gas_limit),
+            };
+            let core_input =
+                CoreInput::new(EVM_DELEGATECALL_METHOD_ID, method_data.encode_to_vec(0));
+            (ECL_CONTRACT_ADDRESS, core_input.encode_to_vec(0))
+        }
+        CallScheme::CallCode if is_evm_account => {
+            let method_data = EvmCallcodeMethodInput {
+                callee_address20: frame.code_address.into_array(),
+                value32: frame.value.to_be_bytes(),
+                args: frame.input.to_vec(),
+                gas_limit: gas_limit32(frame.gas_limit),
+            };
+            let core_input = CoreInput::new(EVM_CALLCODE_METHOD_ID, method_data.encode_to_vec(0));
+            (ECL_CONTRACT_ADDRESS, core_input.encode_to_vec(0))
+        }
+        _ => (frame.code_address, frame.input.to_vec()),
+    };
+    // EVM loader transfers value by itself through ECL, so here it's done for other callees only
+    let transfer = if transfers_value && !is_evm_account {
+        frame.value
+    } else {
+        U256::ZERO
+    };
+    let mut contract_input = ExecutionContext::contract_input_full();
+    contract_input.contract_address = frame.address;
+    contract_input.contract_caller = frame.caller;
+    contract_input.contract_value = frame.value;
+    contract_input.contract_is_static = frame.is_static;
+    exec_contract(
+        &code_address,
+        contract_input,
+        &input,
+        frame.gas_limit,
+        transfer,
+    )
+}
+
+/// Deploys init code on behalf of the caller, it's shared by the SDK creates and the EVM
+/// interpreter's sub creates.
+///
+/// Init code is deployed by ECL or WCL (depending on the bytecode type) in the same way as it's
+/// done for transactions, the output of the successful create is the 20-byte address of the new
+/// contract.
+pub fn dispatch_create(
+    caller: Address,
+    value: U256,
+    init_code: &[u8],
+    salt: Option<U256>,
+    gas_limit: u64,
+) -> FrameResult {
+    if ExecutionContext::contract_is_static() {
+        return FrameResult::from_exit_code(ExitCode::WriteProtection);
+    }
+    let value32 = value.to_be_bytes();
+    let code = init_code.to_vec();
+    let gas_limit32 = gas_limit32(gas_limit);
+    let (loader_address, core_input) = match (is_wasm_bytecode(init_code), salt) {
+        (false, None) => {
+            let method_data = EvmCreateMethodInput {
+                value32,
+                code,
+                gas_limit: gas_limit32,
+            };
+            (
+                ECL_CONTRACT_ADDRESS,
+                CoreInput::new(EVM_CREATE_METHOD_ID, method_data.encode_to_vec(0)),
+            )
+        }
+        (false, Some(salt)) => {
+            let method_data = EvmCreate2MethodInput {
+                value32,
+                salt32: salt.to_be_bytes(),
+                code,
+                gas_limit: gas_limit32,
+            };
+            (
+                ECL_CONTRACT_ADDRESS,
+                CoreInput::new(EVM_CREATE2_METHOD_ID, method_data.encode_to_vec(0)),
+            )
+        }
+        (true, None) => {
+            let method_data = WasmCreateMethodInput {
+                value32,
+                code,
+                gas_limit: gas_limit32,
+            };
+            (
+                WCL_CONTRACT_ADDRESS,
+                CoreInput::new(WASM_CREATE_METHOD_ID, method_data.encode_to_vec(0)),
+            )
+        }
+        (true, Some(salt)) => {
+            let method_data = WasmCreate2MethodInput {
+                value32,
+                salt32: salt.to_be_bytes(),
+                code,
+                gas_limit: gas_limit32,
+            };
+            (
+                WCL_CONTRACT_ADDRESS,
+                CoreInput::new(WASM_CREATE2_METHOD_ID, method_data.encode_to_vec(0)),
+            )
+        }
+    };
+    let mut contract_input = ExecutionContext::contract_input_full();
+    contract_input.contract_address = loader_address;
+    contract_input.contract_caller = caller;
+    contract_input.contract_value = value;
+    contract_input.contract_is_static = false;
+    let mut result = exec_contract(
+        &loader_address,
+        contract_input,
+        &core_input.encode_to_vec(0),
+        gas_limit,
+        U256::ZERO,
+    );
+    if result.exit_code == ExitCode::Ok.into_i32() && result.output.len() != 20 {
+        result.exit_code = ExitCode::CreateError.into_i32();
+    }
+    result
+}
+
+/// Executes rWASM bytecode of the code account with the contract input, the value is transferred
+/// from the context's caller to the context's address under the same checkpoint as the execution,
+/// so it's reverted together with the callee's changes
+fn exec_contract(
+    code_address: &Address,
+    mut contract_input: ContractInput,
+    input: &[u8],
+    gas_limit: u64,
+    value: U256,
+) -> FrameResult {
+    // check call stack limit, gas isn't spent if the frame isn't created
+    let depth = contract_input.contract_depth;
+    if depth >= CALL_STACK_LIMIT {
+        return FrameResult::from_exit_code(ExitCode::CallDepthOverflow);
+    }
+    let checkpoint = LowLevelSDK::jzkt_checkpoint();
+    if value != U256::ZERO {
+        let from = contract_input.contract_caller;
+        let to = contract_input.contract_address;
+        if let Err(exit_code) = transfer_value(&from, &to, value) {
+            LowLevelSDK::jzkt_rollback(checkpoint);
+            return FrameResult::from_exit_code(exit_code);
+        }
+    }
+    let bytecode_hash = rwasm_bytecode_hash(code_address);
+    // there is nothing to execute for accounts w/o code
+    if bytecode_hash == B256::ZERO || bytecode_hash == POSEIDON_EMPTY {
+        return FrameResult::from_exit_code(ExitCode::Ok);
+    }
+    contract_input.journal_checkpoint = checkpoint;
+    contract_input.contract_gas_limit = gas_limit;
+    contract_input.contract_input = Bytes::copy_from_slice(input);
+    // WCL runs the constructor in a nested frame and increases depth for it by itself, other
+    // callees (including ECL that runs EVM bytecode in place) are new frames
+    if *code_address != WCL_CONTRACT_ADDRESS {
+        contract_input.contract_depth = depth + 1;
+    }
+    // static mode must be enforced by the runtime for the callee and all its nested calls
+    let state = if contract_input.contract_is_static {
+        STATE_MAIN | STATE_STATIC_FLAG
+    } else {
+        STATE_MAIN
+    };
+    let contract_input = contract_input.encode_to_vec(0);
+    let fuel_limit = ExecutionContext::gas_to_fuel(gas_limit);
+    let mut fuel = fuel_limit;
+    let exit_code = LowLevelSDK::sys_exec_hash(
+        bytecode_hash.as_ptr(),
+        contract_input.as_ptr(),
+        contract_input.len() as u32,
+        core::ptr::null_mut(),
+        0,
+        &mut fuel as *mut u64,
+        state,
+    );
+    let output = read_output();
+    if exit_code != ExitCode::Ok.into_i32() {
+        // revert all changes made by the callee, output is kept as a revert reason
+        LowLevelSDK::jzkt_rollback(checkpoint);
+    }
+    FrameResult {
+        exit_code,
+        output,
+        // partially consumed gas is rounded up, so it can't exceed the gas limit
+        gas_used: ExecutionContext::fuel_to_gas(fuel_limit - fuel).min(gas_limit),
+    }
+}
+
+fn read_output() -> Bytes {
+    let output_size = LowLevelSDK::sys_output_size();
+    let mut output: Vec<u8> = vec![0u8; output_size as usize];
+    LowLevelSDK::sys_read_output(output.as_mut_ptr(), 0, output_size);
+    output.into()
+}
+
+fn rwasm_bytecode_hash(address: &Address) -> B256 {
+    let mut bytecode_hash = B256::ZERO;
+    LowLevelSDK::jzkt_get(
+        address.into_word().as_ptr(),
+        JZKT_ACCOUNT_RWASM_BYTECODE_HASH_FIELD,
+        bytecode_hash.as_mut_ptr(),
+    );
+    bytecode_hash
+}
+
+/// Returns size of the account's source bytecode and its first 4 bytes, only the magic is read
+/// from the preimage, so the bytecode isn't copied
+fn source_bytecode_magic(address: &Address) -> (u32, [u8; 4]) {
+    let mut source_bytecode_hash = B256::ZERO;
+    LowLevelSDK::jzkt_get(
+        address.into_word().as_ptr(),
+        JZKT_ACCOUNT_SOURCE_BYTECODE_HASH_FIELD,
+        source_bytecode_hash.as_mut_ptr(),
+    );
+    let source_bytecode_size = LowLevelSDK::jzkt_preimage_size(source_bytecode_hash.as_ptr());
+    let mut magic = [0u8; 4];
+    if source_bytecode_size >= magic.len() as u32 {
+        LowLevelSDK::jzkt_preimage_read(
+            source_bytecode_hash.as_ptr(),
+            magic.as_mut_ptr(),
+            0,
+            magic.len() as u32,
+        );
+    }
+    (source_bytecode_size, magic)
+}
+
+/// EVM accounts store EVM bytecode as a source and the EVM loader as an rWASM bytecode
+fn is_evm_account(address: &Address) -> bool {
+    let (source_bytecode_size, magic) = source_bytecode_magic(address);
+    source_bytecode_size > 0 && magic != WASM_MAGIC
+}
+
+fn is_wasm_bytecode(bytecode: &[u8]) -> bool {
+    bytecode.starts_with(&WASM_MAGIC)
+}
+
+fn account_fields(address: &Address) -> [[u8; 32]; JZKT_ACCOUNT_FIELDS_COUNT as usize] {
+    let mut fields = [[0u8; 32]; JZKT_ACCOUNT_FIELDS_COUNT as usize];
+    for (i, field) in fields.iter_mut().enumerate() {
+        LowLevelSDK::jzkt_get(address.into_word().as_ptr(), i as u32, field.as_mut_ptr());
+    }
+    fields
+}
+
+/// Transfers value between accounts, if it's sent to the same account (CALLCODE) then only the
+/// balance is checked
+fn transfer_value(from: &Address, to: &Address, value: U256) -> Result<(), ExitCode> {
+    const BALANCE: usize = JZKT_ACCOUNT_BALANCE_FIELD as usize;
+    let mut from_fields = account_fields(from);
+    let from_balance = U256::from_le_bytes(from_fields[BALANCE])
+        .checked_sub(value)
+        .ok_or(ExitCode::InsufficientBalance)?;
+    if from == to {
+        return Ok(());
+    }
+    let mut to_fields = account_fields(to);
+    let to_balance = U256::from_le_bytes(to_fields[BALANCE])
+        .checked_add(value)
+        .ok_or(ExitCode::OverflowPayment)?;
+    from_fields[BALANCE] = from_balance.to_le_bytes();
+    to_fields[BALANCE] = to_balance.to_le_bytes();
+    for (address, fields) in [(from, &from_fields), (to, &to_fields)] {
+        LowLevelSDK::jzkt_update(
+            address.into_word().as_ptr(),
+            JZKT_COMPRESSION_FLAGS,
+            fields.as_ptr(),
+            32 * fields.len() as u32,
+        );
+    }
+    Ok(())
+}
+
+#[inline(always)]
+fn gas_limit32(gas_limit: u64) -> u32 {
+    gas_limit.min(u32::MAX as u64) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fluentbase_types::address;
+
+    fn deploy_rwasm(address: &Address, rwasm_bytecode: &[u8]) {
+        deploy_contract(address, &[], rwasm_bytecode);
+    }
+
+    fn deploy_contract(address: &Address, source_bytecode: &[u8], rwasm_bytecode: &[u8]) {
+        let mut source_bytecode_hash = B256::ZERO;
+        LowLevelSDK::crypto_keccak256(
+            source_bytecode.as_ptr(),
+            source_bytecode.len() as u32,
+            source_bytecode_hash.as_mut_ptr(),
+        );
+        let mut bytecode_hash = B256::ZERO;
+        LowLevelSDK::crypto_poseidon(
+            rwasm_bytecode.as_ptr(),
+            rwasm_bytecode.len() as u32,
+            bytecode_hash.as_mut_ptr(),
+        );
+        let mut fields = [[0u8; 32]; JZKT_ACCOUNT_FIELDS_COUNT as usize];
+        fields[JZKT_ACCOUNT_SOURCE_BYTECODE_HASH_FIELD as usize] = source_bytecode_hash.0;
+        fields[JZKT_ACCOUNT_RWASM_BYTECODE_HASH_FIELD as usize] = bytecode_hash.0;
+        let address_word = address.into_word();
+        LowLevelSDK::jzkt_update(
+            address_word.as_ptr(),
+            0,
+            fields.as_ptr(),
+            32 * fields.len() as u32,
+        );
+        if !source_bytecode.is_empty() {
+            LowLevelSDK::jzkt_update_preimage(
+                address_word.as_ptr(),
+                JZKT_ACCOUNT_SOURCE_BYTECODE_HASH_FIELD,
+                source_bytecode.as_ptr(),
+                source_bytecode.len() as u32,
+            );
+        }
+        LowLevelSDK::jzkt_update_preimage(
+            address_word.as_ptr(),
+            JZKT_ACCOUNT_RWASM_BYTECODE_HASH_FIELD,
+            rwasm_bytecode.as_ptr(),
+            rwasm_bytecode.len() as u32,
+        );
+    }
+
+    fn with_contract_input(is_static: bool) {
+        with_contract_input_at_depth(is_static, 0);
+    }
+
+    fn with_contract_input_at_depth(is_static: bool, depth: u64) {
+        let contract_input = ContractInput {
+            contract_address: address!("0000000000000000000000000000000000000001"),
+            contract_is_static: is_static,
+            contract_depth: depth,
+            ..Default::default()
+        };
+        LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+        LowLevelSDK::with_default_jzkt();
+    }
+
+    fn set_balance(address: &Address, balance: U256) {
+        let mut fields = account_fields(address);
+        fields[JZKT_ACCOUNT_BALANCE_FIELD as usize] = balance.to_le_bytes();
+        LowLevelSDK::jzkt_update(
+            address.into_word().as_ptr(),
+            JZKT_COMPRESSION_FLAGS,
+            fields.as_ptr(),
+            32 * fields.len() as u32,
+        );
+    }
+
+    fn balance(address: &Address) -> U256 {
+        U256::from_le_bytes(account_fields(address)[JZKT_ACCOUNT_BALANCE_FIELD as usize])
+    }
+
+    #[test]
+    fn test_call_wasm_contract() {
+        with_contract_input(false);
+        let callee = address!("0000000000000000000000000000000000000002");
+        deploy_rwasm(
+            &callee,
+            include_bytes!("../../../examples/bin/greeting.rwasm"),
+        );
+        let output = call(callee, U256::ZERO, &[], 10_000_000).unwrap();
+        assert_eq!(output.as_ref(), "Hello, World".as_bytes());
+        let output = static_call(callee, &[], 10_000_000).unwrap();
+        assert_eq!(output.as_ref(), "Hello, World".as_bytes());
+    }
+
+    #[test]
+    fn test_delegate_call_wasm_contract() {
+        with_contract_input(false);
+        let callee = address!("0000000000000000000000000000000000000002");
+        deploy_contract(
+            &callee,
+            include_bytes!("../../../examples/bin/greeting.wasm"),
+            include_bytes!("../../../examples/bin/greeting.rwasm"),
+        );
+        let output = delegate_call(callee, &[], 10_000_000).unwrap();
+        assert_eq!(output.as_ref(), "Hello, World".as_bytes());
+        let output = call_code(callee, U256::ZERO, &[], 10_000_000).unwrap();
+        assert_eq!(output.as_ref(), "Hello, World".as_bytes());
+    }
+
+    #[test]
+    fn test_is_evm_account() {
+        with_contract_input(false);
+        let wasm_account = address!("0000000000000000000000000000000000000002");
+        deploy_contract(
+            &wasm_account,
+            include_bytes!("../../../examples/bin/greeting.wasm"),
+            include_bytes!("../../../examples/bin/greeting.rwasm"),
+        );
+        let evm_account = address!("0000000000000000000000000000000000000003");
+        // PUSH1 0 PUSH1 0 RETURN
+        deploy_contract(
+            &evm_account,
+            &[0x60, 0x00, 0x60, 0x00, 0xf3],
+            include_bytes!("../../../examples/bin/greeting.rwasm"),
+        );
+        let empty_account = address!("0000000000000000000000000000000000000004");
+        assert!(!is_evm_account(&wasm_account));
+        assert!(is_evm_account(&evm_account));
+        assert!(!is_evm_account(&empty_account));
+    }
+
+    #[test]
+    fn test_call_failed_contract() {
+        with_contract_input(false);
+        let callee = address!("0000000000000000000000000000000000000002");
+        deploy_rwasm(&callee, include_bytes!("../../../examples/bin/panic.rwasm"));
+        let err = call(callee, U256::ZERO, &[], 10_000_000).unwrap_err();
+        assert_eq!(err.exit_code, ExitCode::Panic.into_i32());
+    }
+
+    #[test]
+    fn test_call_empty_account() {
+        with_contract_input(false);
+        let callee = address!("0000000000000000000000000000000000000002");
+        let output = call(callee, U256::ZERO, &[], 10_000_000).unwrap();
+        assert!(output.is_empty());
+    }
+
+    #[test]
+    fn test_call_with_value() {
+        with_contract_input(false);
+        let caller = address!("0000000000000000000000000000000000000001");
+        let callee = address!("0000000000000000000000000000000000000002");
+        set_balance(&caller, U256::from(10));
+        deploy_rwasm(
+            &callee,
+            include_bytes!("../../../examples/bin/greeting.rwasm"),
+        );
+        call(callee, U256::from(3), &[], 10_000_000).unwrap();
+        assert_eq!(balance(&caller), U256::from(7));
+        assert_eq!(balance(&callee), U256::from(3));
+        // value is transferred to accounts w/o code as well
+        let empty = address!("0000000000000000000000000000000000000003");
+        call(empty, U256::from(2), &[], 10_000_000).unwrap();
+        assert_eq!(balance(&caller), U256::from(5));
+        assert_eq!(balance(&empty), U256::from(2));
+        let err = call(callee, U256::from(6), &[], 10_000_000).unwrap_err();
+        assert_eq!(err.exit_code, ExitCode::InsufficientBalance.into_i32());
+        assert_eq!(balance(&caller), U256::from(5));
+        assert_eq!(balance(&callee), U256::from(3));
+    }
+
+    #[test]
+    fn test_call_code_with_value() {
+        with_contract_input(false);
+        let caller = address!("0000000000000000000000000000000000000001");
+        let callee = address!("0000000000000000000000000000000000000002");
+        set_balance(&caller, U256::from(10));
+        deploy_contract(
+            &callee,
+            include_bytes!("../../../examples/bin/greeting.wasm"),
+            include_bytes!("../../../examples/bin/greeting.rwasm"),
+        );
+        call_code(callee, U256::from(10), &[], 10_000_000).unwrap();
+        assert_eq!(balance(&caller), U256::from(10));
+        assert_eq!(balance(&callee), U256::ZERO);
+        let err = call_code(callee, U256::from(11), &[], 10_000_000).unwrap_err();
+        assert_eq!(err.exit_code, ExitCode::InsufficientBalance.into_i32());
+    }
+
+    #[test]
+    fn test_call_depth_limit() {
+        let callee = address!("0000000000000000000000000000000000000002");
+        with_contract_input_at_depth(false, CALL_STACK_LIMIT - 1);
+        deploy_rwasm(
+            &callee,
+            include_bytes!("../../../examples/bin/greeting.rwasm"),
+        );
+        call(callee, U256::ZERO, &[], 10_000_000).unwrap();
+        with_contract_input_at_depth(false, CALL_STACK_LIMIT);
+        let err = call(callee, U256::ZERO, &[], 10_000_000).unwrap_err();
+        assert_eq!(err.exit_code, ExitCode::CallDepthOverflow.into_i32());
+        let err = create(U256::ZERO, &[], 10_000_000).unwrap_err();
+        assert_eq!(err.exit_code, ExitCode::CallDepthOverflow.into_i32());
+    }
+
+    #[test]
+    fn test_static_call_with_value() {
+        with_contract_input(true);
+        let callee = address!("0000000000000000000000000000000000000002");
+        let err = call(callee, U256::from(1), &[], 10_000_000).unwrap_err();
+        assert_eq!(err.exit_code, ExitCode::WriteProtection.into_i32());
+        let err = call_code(callee, U256::from(1), &[], 10_000_000).unwrap_err();
+        assert_eq!(err.exit_code, ExitCode::WriteProtection.into_i32());
+        let err = create(U256::ZERO, &[], 10_000_000).unwrap_err();
+        assert_eq!(err.exit_code, ExitCode::WriteProtection.into_i32());
+    }
+}
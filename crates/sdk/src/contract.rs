@@ -0,0 +1,111 @@
+use crate::{LowLevelAPI, LowLevelSDK};
+use alloc::{string::ToString, vec, vec::Vec};
+use core::fmt::Display;
+use fluentbase_codec::Encoder;
+use fluentbase_types::ExitCode;
+
+/// Selector of Solidity's `Error(string)`
+pub const ERROR_STRING_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Encodes revert reason in the same way as Solidity does for `revert("reason")`
+pub fn encode_revert_reason(reason: &str) -> Vec<u8> {
+    let padded_len = reason.len().div_ceil(32) * 32;
+    let mut result = vec![0u8; 4 + 32 + 32 + padded_len];
+    result[0..4].copy_from_slice(&ERROR_STRING_SELECTOR);
+    // offset of the string data
+    result[4 + 31] = 0x20;
+    result[4 + 32 + 24..4 + 64].copy_from_slice(&(reason.len() as u64).to_be_bytes());
+    result[4 + 64..4 + 64 + reason.len()].copy_from_slice(reason.as_bytes());
+    result
+}
+
+/// Writes encoded value into the output
+pub fn write_output<T: Encoder<T>>(value: &T) {
+    LowLevelSDK::sys_write(value.encode_to_vec(0).as_slice());
+}
+
+/// Reverts execution with the reason
+pub fn revert<E: Display>(reason: E) {
+    LowLevelSDK::sys_write(encode_revert_reason(&reason.to_string()).as_slice());
+    LowLevelSDK::sys_halt(ExitCode::Panic.into_i32());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{contract, evm::ContractInput};
+    use fluentbase_codec::BufferEncoder;
+    use fluentbase_types::{Address, Bytes, U256};
+
+    #[derive(Default)]
+    struct Token;
+
+    #[contract]
+    impl Token {
+        pub fn deploy(&self) {
+            LowLevelSDK::sys_write("deployed".as_bytes());
+        }
+
+        pub fn balance_of(&self, owner: Address) -> U256 {
+            U256::from_be_slice(owner.as_slice())
+        }
+
+        pub fn add(&self, a: u64, b: u64) -> u64 {
+            a + b
+        }
+
+        pub fn transfer(&mut self, _to: Address, value: U256) -> Result<bool, &'static str> {
+            if value > U256::from(100) {
+                return Err("insufficient balance");
+            }
+            Ok(true)
+        }
+    }
+
+    fn call_main(input: Vec<u8>) -> Vec<u8> {
+        let contract_input = ContractInput {
+            contract_input: Bytes::from(input),
+            ..Default::default()
+        };
+        LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+        main();
+        LowLevelSDK::get_test_output()
+    }
+
+    #[test]
+    fn test_selectors() {
+        // keccak256("balanceOf(address)")
+        assert_eq!(Token::BALANCE_OF_SELECTOR, [0x70, 0xa0, 0x82, 0x31]);
+        // keccak256("transfer(address,uint256)")
+        assert_eq!(Token::TRANSFER_SELECTOR, [0xa9, 0x05, 0x9c, 0xbb]);
+    }
+
+    #[test]
+    fn test_deploy() {
+        LowLevelSDK::with_test_input(ContractInput::default().encode_to_vec(0));
+        deploy();
+        assert_eq!(LowLevelSDK::get_test_output(), "deployed".as_bytes());
+    }
+
+    #[test]
+    fn test_dispatch() {
+        let mut input = Token::ADD_SELECTOR.to_vec();
+        let mut buffer_encoder = BufferEncoder::new(16, None);
+        100u64.encode(&mut buffer_encoder, 0);
+        20u64.encode(&mut buffer_encoder, 8);
+        input.extend(buffer_encoder.finalize());
+        let output = call_main(input);
+        assert_eq!(output, 120u64.encode_to_vec(0));
+    }
+
+    #[test]
+    fn test_revert_with_reason() {
+        let mut input = Token::TRANSFER_SELECTOR.to_vec();
+        let mut buffer_encoder = BufferEncoder::new(20 + 32, None);
+        Address::ZERO.encode(&mut buffer_encoder, 0);
+        U256::from(1000).encode(&mut buffer_encoder, 20);
+        input.extend(buffer_encoder.finalize());
+        let output = call_main(input);
+        assert_eq!(output, encode_revert_reason("insufficient balance"));
+    }
+}
@@ -0,0 +1,303 @@
+use crate::{evm::ExecutionContext, LowLevelAPI, LowLevelSDK};
+use alloc::{string::String, vec, vec::Vec};
+use fluentbase_types::{Address, Bytes, B256, U256};
+
+/// A type that can be used as an event field.
+///
+/// Static types are encoded into one 32-byte word, while dynamic types (`bytes` and `string`)
+/// are encoded into the tail of ABI data and are stored as `keccak256` hash if indexed.
+pub trait AbiType {
+    fn is_dynamic(&self) -> bool {
+        false
+    }
+
+    /// Returns a head word for static types
+    fn abi_word(&self) -> [u8; 32];
+
+    /// Writes a tail of dynamic types (length and right-padded data)
+    fn abi_tail(&self, _output: &mut Vec<u8>) {}
+
+    fn topic(&self) -> [u8; 32] {
+        self.abi_word()
+    }
+}
+
+impl AbiType for bool {
+    fn abi_word(&self) -> [u8; 32] {
+        let mut result = [0u8; 32];
+        result[31] = *self as u8;
+        result
+    }
+}
+
+macro_rules! impl_abi_type_uint {
+    ($($typ:ty),*) => {
+        $(
+            impl AbiType for $typ {
+                fn abi_word(&self) -> [u8; 32] {
+                    let bytes = self.to_be_bytes();
+                    let mut result = [0u8; 32];
+                    result[32 - bytes.len()..].copy_from_slice(&bytes);
+                    result
+                }
+            }
+        )*
+    };
+}
+impl_abi_type_uint!(u8, u16, u32, u64, u128);
+
+macro_rules! impl_abi_type_int {
+    ($($typ:ty),*) => {
+        $(
+            impl AbiType for $typ {
+                fn abi_word(&self) -> [u8; 32] {
+                    let bytes = self.to_be_bytes();
+                    // negative values are sign-extended
+                    let mut result = if *self < 0 { [0xffu8; 32] } else { [0u8; 32] };
+                    result[32 - bytes.len()..].copy_from_slice(&bytes);
+                    result
+                }
+            }
+        )*
+    };
+}
+impl_abi_type_int!(i8, i16, i32, i64, i128);
+
+impl AbiType for U256 {
+    fn abi_word(&self) -> [u8; 32] {
+        self.to_be_bytes::<32>()
+    }
+}
+
+impl AbiType for Address {
+    fn abi_word(&self) -> [u8; 32] {
+        self.into_word().0
+    }
+}
+
+impl AbiType for B256 {
+    fn abi_word(&self) -> [u8; 32] {
+        self.0.abi_word()
+    }
+}
+
+struct FixedBytesLen<const N: usize>;
+
+impl<const N: usize> FixedBytesLen<N> {
+    // evaluated for every used `N`, so `bytesN` longer than 32 bytes fails to compile
+    const CHECK: () = assert!(N <= 32, "fixed bytes can't be longer than 32 bytes");
+}
+
+impl<const N: usize> AbiType for [u8; N] {
+    fn abi_word(&self) -> [u8; 32] {
+        let () = FixedBytesLen::<N>::CHECK;
+        // fixed bytes are left-aligned
+        let mut result = [0u8; 32];
+        result[..N].copy_from_slice(self);
+        result
+    }
+}
+
+fn abi_tail_bytes(value: &[u8], output: &mut Vec<u8>) {
+    output.extend_from_slice(&(value.len() as u64).abi_word());
+    output.extend_from_slice(value);
+    output.resize(output.len() + (32 - value.len() % 32) % 32, 0);
+}
+
+fn topic_bytes(value: &[u8]) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    LowLevelSDK::crypto_keccak256(value.as_ptr(), value.len() as u32, result.as_mut_ptr());
+    result
+}
+
+macro_rules! impl_abi_type_dynamic {
+    ($typ:ty, $as_bytes:ident) => {
+        impl AbiType for $typ {
+            fn is_dynamic(&self) -> bool {
+                true
+            }
+            fn abi_word(&self) -> [u8; 32] {
+                unreachable!("dynamic type doesn't have a head word")
+            }
+            fn abi_tail(&self, output: &mut Vec<u8>) {
+                abi_tail_bytes(self.$as_bytes(), output)
+            }
+            fn topic(&self) -> [u8; 32] {
+                topic_bytes(self.$as_bytes())
+            }
+        }
+    };
+}
+impl_abi_type_dynamic!(Bytes, as_ref);
+impl_abi_type_dynamic!(Vec<u8>, as_slice);
+impl_abi_type_dynamic!(String, as_bytes);
+
+/// ABI-encodes values in the same way as `abi.encode(...)` does
+pub fn abi_encode(values: &[&dyn AbiType]) -> Vec<u8> {
+    let heads_len = values.len() * 32;
+    let mut heads = Vec::with_capacity(heads_len);
+    let mut tails = Vec::new();
+    for value in values {
+        if value.is_dynamic() {
+            heads.extend_from_slice(&((heads_len + tails.len()) as u64).abi_word());
+            value.abi_tail(&mut tails);
+        } else {
+            heads.extend_from_slice(&value.abi_word());
+        }
+    }
+    heads.extend(tails);
+    heads
+}
+
+/// Emits log from the current contract
+pub fn emit_log(topics: &[[u8; 32]], data: &[u8]) {
+    let address_word = ExecutionContext::contract_address().into_word();
+    LowLevelSDK::jzkt_emit_log(
+        address_word.as_ptr(),
+        topics.as_ptr(),
+        topics.len() as u32,
+        data.as_ptr(),
+        data.len() as u32,
+    );
+}
+
+/// Solidity compatible event, use `#[derive(Event)]` to implement it
+pub trait Event {
+    const SIGNATURE: &'static str;
+    const SIGNATURE_HASH: [u8; 32];
+    const ANONYMOUS: bool;
+
+    /// Visits every event field in the order of declaration, the second argument says whether
+    /// the field is indexed
+    fn visit_fields<'a>(&'a self, visitor: &mut dyn FnMut(&'a dyn AbiType, bool));
+
+    fn topics(&self) -> Vec<[u8; 32]> {
+        let mut topics = if Self::ANONYMOUS {
+            vec![]
+        } else {
+            vec![Self::SIGNATURE_HASH]
+        };
+        self.visit_fields(&mut |value, is_indexed| {
+            if is_indexed {
+                topics.push(value.topic());
+            }
+        });
+        topics
+    }
+
+    fn data(&self) -> Vec<u8> {
+        let mut values = Vec::<&dyn AbiType>::new();
+        self.visit_fields(&mut |value, is_indexed| {
+            if !is_indexed {
+                values.push(value);
+            }
+        });
+        abi_encode(&values)
+    }
+
+    fn emit(&self) {
+        emit_log(&self.topics(), &self.data());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{evm::ContractInput, Event};
+    use fluentbase_codec::Encoder;
+    use fluentbase_runtime::{IJournaledTrie, JournalCheckpoint};
+    use fluentbase_types::{address, b256};
+
+    #[derive(Event)]
+    struct Transfer {
+        #[indexed]
+        from: Address,
+        #[indexed]
+        to: Address,
+        value: U256,
+    }
+
+    #[derive(Event)]
+    #[anonymous]
+    struct Message {
+        #[indexed]
+        id: u64,
+        #[indexed]
+        text: String,
+        payload: Bytes,
+        flag: bool,
+    }
+
+    #[test]
+    fn test_static_event() {
+        let event = Transfer {
+            from: address!("1000000000000000000000000000000000000001"),
+            to: address!("1000000000000000000000000000000000000002"),
+            value: U256::from(100),
+        };
+        assert_eq!(Transfer::SIGNATURE, "Transfer(address,address,uint256)");
+        assert_eq!(
+            event.topics(),
+            vec![
+                b256!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").0,
+                event.from.into_word().0,
+                event.to.into_word().0,
+            ]
+        );
+        assert_eq!(event.data(), U256::from(100).to_be_bytes::<32>().to_vec());
+    }
+
+    #[test]
+    fn test_dynamic_event() {
+        let event = Message {
+            id: 7,
+            text: String::from("hello"),
+            payload: Bytes::from_static(&[1, 2, 3]),
+            flag: true,
+        };
+        assert_eq!(Message::SIGNATURE, "Message(uint64,string,bytes,bool)");
+        let topics = event.topics();
+        assert_eq!(topics.len(), 2);
+        assert_eq!(topics[0], 7u64.abi_word());
+        assert_eq!(topics[1], topic_bytes("hello".as_bytes()));
+        let data = event.data();
+        assert_eq!(data.len(), 32 * 4);
+        // offset of the payload
+        assert_eq!(data[0..32], 64u64.abi_word());
+        assert_eq!(data[32..64], true.abi_word());
+        assert_eq!(data[64..96], 3u64.abi_word());
+        assert_eq!(data[96..99], [1, 2, 3]);
+        assert_eq!(data[99..128], [0u8; 29]);
+    }
+
+    #[test]
+    fn test_emit_event() {
+        let contract_input = ContractInput {
+            contract_address: address!("0000000000000000000000000000000000000001"),
+            ..Default::default()
+        };
+        LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+        let jzkt = LowLevelSDK::with_default_jzkt();
+        let event = Transfer {
+            from: address!("1000000000000000000000000000000000000001"),
+            to: address!("1000000000000000000000000000000000000002"),
+            value: U256::from(1),
+        };
+        event.emit();
+        let jzkt = jzkt.borrow();
+        let logs = jzkt.logs(JournalCheckpoint(0, 0));
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].address, contract_input.contract_address);
+        assert_eq!(
+            logs[0].topics,
+            event
+                .topics()
+                .into_iter()
+                .map(B256::new)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(logs[0].topics[1], event.from.into_word());
+        assert_eq!(logs[0].topics[2], event.to.into_word());
+        assert_eq!(logs[0].data.as_ref(), U256::from(1).to_be_bytes::<32>());
+    }
+}
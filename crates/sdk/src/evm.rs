@@ -2,6 +2,20 @@ use crate::{LowLevelAPI, LowLevelSDK};
 use alloc::{vec, vec::Vec};
 use fluentbase_codec::{define_codec_struct, BufferDecoder, Encoder};
 pub use fluentbase_types::{Address, Bytes, B256, U256};
+use fluentbase_types::{
+    Bytes32,
+    FUEL_PER_GAS,
+    JZKT_IMMUTABLE_DATA_HASH_FIELD,
+    JZKT_IMMUTABLE_DATA_SIZE_FIELD,
+    WASM_MAGIC,
+};
+
+define_codec_struct! {
+    pub struct AccessListItem {
+        address: Address,
+        storage_keys: Vec<U256>,
+    }
+}
 
 define_codec_struct! {
     pub struct ContractInput {
@@ -9,6 +23,10 @@ define_codec_struct! {
         journal_checkpoint: u64,
         // env info
         env_chain_id: u64,
+        // `None` means that the spec isn't set, so the default one is used
+        env_spec_id: Option<u8>,
+        // amount of fuel per unit of gas, zero means the default ratio
+        env_fuel_per_gas: u64,
         // contract info
         contract_gas_limit: u64,
         contract_address: Address,
@@ -16,6 +34,7 @@ define_codec_struct! {
         contract_input: Bytes,
         contract_value: U256,
         contract_is_static: bool,
+        contract_depth: u64,
         // block info
         block_coinbase: Address,
         block_timestamp: u64,
@@ -23,15 +42,53 @@ define_codec_struct! {
         block_difficulty: u64,
         block_gas_limit: u64,
         block_base_fee: U256,
+        block_prevrandao: B256,
         // tx info
         tx_gas_price: U256,
         tx_gas_priority_fee: Option<U256>,
         tx_caller: Address,
-        // tx_blob_hashes: Vec<B256>,
-        // tx_blob_gas_price: u64,
+        tx_gas_limit: u64,
+        tx_nonce: u64,
+        tx_access_list: Vec<AccessListItem>,
+        tx_blob_hashes: Vec<B256>,
+        tx_blob_gas_price: U256,
+    }
+}
+
+// WASM contracts are deployed either with a raw WASM binary or with this envelope, in the last
+// case `args` are passed to the deploy function as the contract input
+define_codec_struct! {
+    pub struct WasmDeployInput {
+        wasm: Bytes,
+        args: Bytes,
+    }
+}
+
+impl WasmDeployInput {
+    /// Encodes the envelope prefixed with the WASM magic, so it's deployed as a WASM contract
+    pub fn encode_deploy_code(&self) -> Vec<u8> {
+        let mut result = WASM_MAGIC.to_vec();
+        result.extend_from_slice(&self.encode_to_vec(0));
+        result
     }
 }
 
+const IMMUTABLE_DATA_KEY_SUFFIX: &[u8] = b"immutable_data";
+
+/// Returns the trie key of the entry with immutable data of the account
+pub fn immutable_data_key(address: &Address) -> B256 {
+    let mut preimage = [0u8; 20 + IMMUTABLE_DATA_KEY_SUFFIX.len()];
+    preimage[..20].copy_from_slice(address.as_slice());
+    preimage[20..].copy_from_slice(IMMUTABLE_DATA_KEY_SUFFIX);
+    let mut result = B256::ZERO;
+    LowLevelSDK::crypto_keccak256(
+        preimage.as_ptr(),
+        preimage.len() as u32,
+        result.as_mut_ptr(),
+    );
+    result
+}
+
 macro_rules! impl_reader_helper {
     (@header $input_type:ty, $return_typ:ty) => {
         let mut buffer: [u8; <$input_type>::FIELD_SIZE] = [0; <$input_type>::FIELD_SIZE];
@@ -105,6 +162,8 @@ impl ExecutionContext {
     impl_reader_func!(fn journal_checkpoint() -> u64, <ContractInput as IContractInput>::JournalCheckpoint);
     // env info
     impl_reader_func!(fn env_chain_id() -> u64, <ContractInput as IContractInput>::EnvChainId);
+    impl_reader_func!(fn env_spec_id() -> Option<u8>, <ContractInput as IContractInput>::EnvSpecId);
+    impl_reader_func!(fn env_fuel_per_gas() -> u64, <ContractInput as IContractInput>::EnvFuelPerGas);
     // contract info
     impl_reader_func!(fn contract_gas_limit() -> u64, <ContractInput as IContractInput>::ContractGasLimit);
     impl_reader_func!(fn contract_address() -> Address, <ContractInput as IContractInput>::ContractAddress);
@@ -112,6 +171,7 @@ impl ExecutionContext {
     impl_reader_func!(@dynamic fn contract_input() -> Bytes, <ContractInput as IContractInput>::ContractInput);
     impl_reader_func!(fn contract_value() -> U256, <ContractInput as IContractInput>::ContractValue);
     impl_reader_func!(fn contract_is_static() -> bool, <ContractInput as IContractInput>::ContractIsStatic);
+    impl_reader_func!(fn contract_depth() -> u64, <ContractInput as IContractInput>::ContractDepth);
     // block info
     impl_reader_func!(fn block_coinbase() -> Address, <ContractInput as IContractInput>::BlockCoinbase);
     impl_reader_func!(fn block_timestamp() -> u64, <ContractInput as IContractInput>::BlockTimestamp);
@@ -119,10 +179,16 @@ impl ExecutionContext {
     impl_reader_func!(fn block_difficulty() -> u64, <ContractInput as IContractInput>::BlockDifficulty);
     impl_reader_func!(fn block_gas_limit() -> u64, <ContractInput as IContractInput>::BlockGasLimit);
     impl_reader_func!(fn block_base_fee() -> U256, <ContractInput as IContractInput>::BlockBaseFee);
+    impl_reader_func!(fn block_prevrandao() -> B256, <ContractInput as IContractInput>::BlockPrevrandao);
     // tx info
     impl_reader_func!(fn tx_gas_price() -> U256, <ContractInput as IContractInput>::TxGasPrice);
     impl_reader_func!(fn tx_gas_priority_fee() -> Option<U256>, <ContractInput as IContractInput>::TxGasPriorityFee);
     impl_reader_func!(fn tx_caller() -> Address, <ContractInput as IContractInput>::TxCaller);
+    impl_reader_func!(fn tx_gas_limit() -> u64, <ContractInput as IContractInput>::TxGasLimit);
+    impl_reader_func!(fn tx_nonce() -> u64, <ContractInput as IContractInput>::TxNonce);
+    impl_reader_func!(@dynamic fn tx_access_list() -> Vec<AccessListItem>, <ContractInput as IContractInput>::TxAccessList);
+    impl_reader_func!(@dynamic fn tx_blob_hashes() -> Vec<B256>, <ContractInput as IContractInput>::TxBlobHashes);
+    impl_reader_func!(fn tx_blob_gas_price() -> U256, <ContractInput as IContractInput>::TxBlobGasPrice);
 
     pub fn static_return_and_exit<const N: usize>(
         &self,
@@ -142,6 +208,24 @@ impl ExecutionContext {
         LowLevelSDK::sys_halt(exit_code);
     }
 
+    /// Converts gas limit into fuel limit using the ratio of the current execution
+    pub fn gas_to_fuel(gas: u64) -> u64 {
+        gas.saturating_mul(Self::fuel_per_gas())
+    }
+
+    /// Converts consumed fuel into gas using the ratio of the current execution, partially
+    /// consumed unit of gas is rounded up
+    pub fn fuel_to_gas(fuel: u64) -> u64 {
+        fuel.div_ceil(Self::fuel_per_gas())
+    }
+
+    fn fuel_per_gas() -> u64 {
+        match Self::env_fuel_per_gas() {
+            0 => FUEL_PER_GAS,
+            fuel_per_gas => fuel_per_gas,
+        }
+    }
+
     pub fn raw_input() -> Vec<u8> {
         let input_size = LowLevelSDK::sys_input_size();
         let mut buffer = vec![0u8; input_size as usize];
@@ -149,6 +233,31 @@ impl ExecutionContext {
         buffer
     }
 
+    /// Returns data the contract returned from its deploy function, it's empty if nothing was
+    /// returned
+    pub fn contract_immutable_data() -> Bytes {
+        let key = immutable_data_key(&Self::contract_address());
+        let mut size = Bytes32::default();
+        LowLevelSDK::jzkt_get(
+            key.as_ptr(),
+            JZKT_IMMUTABLE_DATA_SIZE_FIELD,
+            size.as_mut_ptr(),
+        );
+        let size = u64::from_le_bytes(size[..8].try_into().unwrap()) as usize;
+        if size == 0 {
+            return Bytes::new();
+        }
+        let mut hash = Bytes32::default();
+        LowLevelSDK::jzkt_get(
+            key.as_ptr(),
+            JZKT_IMMUTABLE_DATA_HASH_FIELD,
+            hash.as_mut_ptr(),
+        );
+        let mut data = vec![0u8; size];
+        LowLevelSDK::jzkt_preimage_copy(hash.as_ptr(), data.as_mut_ptr());
+        data.into()
+    }
+
     pub fn contract_input_full() -> ContractInput {
         let input = Self::raw_input();
         let mut contract_input = ContractInput::default();
@@ -161,11 +270,11 @@ impl ExecutionContext {
 #[cfg(test)]
 mod test {
     use crate::{
-        evm::{ContractInput, ExecutionContext},
+        evm::{AccessListItem, ContractInput, ExecutionContext},
         LowLevelSDK,
     };
     use fluentbase_codec::Encoder;
-    use fluentbase_types::Bytes;
+    use fluentbase_types::{Address, Bytes, B256, FUEL_PER_GAS, U256};
 
     #[test]
     fn test_encode_decode() {
@@ -180,4 +289,70 @@ mod test {
         let input = ExecutionContext::contract_input();
         assert_eq!(input, contract_input.contract_input);
     }
+
+    #[test]
+    fn test_encode_decode_tx_extensions() {
+        let contract_input = ContractInput {
+            contract_input: Bytes::from_static(&[0, 1, 2, 3]),
+            contract_depth: 3,
+            block_prevrandao: B256::repeat_byte(0x07),
+            tx_gas_limit: 21_000,
+            tx_nonce: 5,
+            tx_access_list: vec![AccessListItem {
+                address: Address::repeat_byte(0x01),
+                storage_keys: vec![U256::from(1), U256::from(2)],
+            }],
+            tx_blob_hashes: vec![B256::repeat_byte(0x01), B256::repeat_byte(0x02)],
+            tx_blob_gas_price: U256::from(100),
+            ..Default::default()
+        };
+        let encoded_input = contract_input.encode_to_vec(0);
+        LowLevelSDK::with_test_input(encoded_input);
+        assert_eq!(
+            ExecutionContext::block_prevrandao(),
+            contract_input.block_prevrandao
+        );
+        assert_eq!(ExecutionContext::contract_depth(), 3);
+        assert_eq!(ExecutionContext::tx_gas_limit(), 21_000);
+        assert_eq!(ExecutionContext::tx_nonce(), 5);
+        assert_eq!(
+            ExecutionContext::tx_access_list(),
+            contract_input.tx_access_list
+        );
+        assert_eq!(
+            ExecutionContext::tx_blob_hashes(),
+            contract_input.tx_blob_hashes
+        );
+        assert_eq!(ExecutionContext::tx_blob_gas_price(), U256::from(100));
+        assert_eq!(ExecutionContext::contract_input_full(), contract_input);
+    }
+
+    #[test]
+    fn test_spec_id_encoding() {
+        LowLevelSDK::with_test_input(ContractInput::default().encode_to_vec(0));
+        assert_eq!(ExecutionContext::env_spec_id(), None);
+        // zero is a valid spec id (frontier), so it must differ from the unset value
+        for spec_id in [0, 17, u8::MAX] {
+            let contract_input = ContractInput {
+                env_spec_id: Some(spec_id),
+                ..Default::default()
+            };
+            LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+            assert_eq!(ExecutionContext::env_spec_id(), Some(spec_id));
+        }
+    }
+
+    #[test]
+    fn test_fuel_per_gas() {
+        LowLevelSDK::with_test_input(ContractInput::default().encode_to_vec(0));
+        assert_eq!(ExecutionContext::gas_to_fuel(5), 5 * FUEL_PER_GAS);
+        let contract_input = ContractInput {
+            env_fuel_per_gas: 10,
+            ..Default::default()
+        };
+        LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+        assert_eq!(ExecutionContext::gas_to_fuel(5), 50);
+        assert_eq!(ExecutionContext::fuel_to_gas(51), 6);
+        assert_eq!(ExecutionContext::gas_to_fuel(u64::MAX), u64::MAX);
+    }
 }
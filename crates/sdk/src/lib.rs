@@ -4,11 +4,17 @@
 extern crate alloc;
 extern crate core;
 extern crate lol_alloc;
+#[cfg(test)]
+extern crate self as fluentbase_sdk;
 
 pub struct LowLevelSDK;
 
+pub mod call;
+pub mod contract;
+pub mod event;
 pub mod evm;
 mod sdk;
+pub mod storage;
 
 pub use sdk::LowLevelAPI;
 
@@ -20,6 +26,8 @@ mod runtime;
 mod rwasm;
 mod types;
 
+pub use fluentbase_codec as codec;
+pub use fluentbase_sdk_macros::{contract, Event};
 pub use fluentbase_types::{Bytes20, Bytes32};
 
 #[cfg(not(feature = "std"))]
@@ -1,6 +1,5 @@
 use crate::{evm::B256, LowLevelAPI, LowLevelSDK};
 use alloc::rc::Rc;
-use byteorder::{ByteOrder, LittleEndian};
 use fluentbase_runtime::{
     instruction::{
         crypto_ecrecover::CryptoEcrecover,
@@ -12,11 +11,14 @@ use fluentbase_runtime::{
         jzkt_compute_root::JzktComputeRoot,
         jzkt_emit_log::JzktEmitLog,
         jzkt_get::JzktGet,
+        jzkt_load::JzktLoad,
         jzkt_open::JzktOpen,
         jzkt_preimage_copy::JzktPreimageCopy,
+        jzkt_preimage_read::JzktPreimageRead,
         jzkt_preimage_size::JzktPreimageSize,
         jzkt_remove::JzktRemove,
         jzkt_rollback::JzktRollback,
+        jzkt_store::JzktStore,
         jzkt_update::JzktUpdate,
         jzkt_update_preimage::JzktUpdatePreimage,
         sys_exec::SysExec,
@@ -37,6 +39,7 @@ use fluentbase_runtime::{
     JournaledTrie,
     RuntimeContext,
 };
+use fluentbase_types::FuelSchedule;
 use std::{cell::RefCell, ptr};
 
 thread_local! {
@@ -57,7 +60,7 @@ where
 
 fn with_context_mut<F, R>(func: F) -> R
 where
-    F: Fn(&mut RuntimeContext<'static, ()>) -> R,
+    F: FnOnce(&mut RuntimeContext<'static, ()>) -> R,
 {
     CONTEXT.with(|ctx| {
         let mut ctx2 = ctx.take();
@@ -153,34 +156,23 @@ impl LowLevelAPI for LowLevelSDK {
         input_len: u32,
         return_offset: *mut u8,
         return_len: u32,
-        fuel_offset: *const u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> i32 {
         let bytecode =
             unsafe { &*ptr::slice_from_raw_parts(code_offset, code_len as usize) }.to_vec();
         let input =
             unsafe { &*ptr::slice_from_raw_parts(input_offset, input_len as usize) }.to_vec();
-        let fuel = LittleEndian::read_u32(unsafe {
-            &*ptr::slice_from_raw_parts(fuel_offset as *const u8, 4)
+        let mut fuel = unsafe { *fuel_offset };
+        let result = with_context_mut(|ctx| {
+            SysExec::fn_impl(ctx, bytecode, input, return_len, &mut fuel, state)
         });
-        match with_context_mut(move |ctx| {
-            SysExec::fn_impl(
-                ctx,
-                bytecode.clone(),
-                input.clone(),
-                return_len,
-                fuel,
-                state,
-            )
-        }) {
-            Ok((result, remaining_fuel)) => {
+        unsafe { *fuel_offset = fuel };
+        match result {
+            Ok(result) => {
                 if return_len > 0 {
                     unsafe { ptr::copy(result.as_ptr(), return_offset, return_len as usize) }
                 }
-                LittleEndian::write_u32(
-                    unsafe { &mut *ptr::slice_from_raw_parts_mut(fuel_offset as *mut u8, 4) },
-                    remaining_fuel,
-                );
                 0
             }
             Err(err) => err,
@@ -193,33 +185,29 @@ impl LowLevelAPI for LowLevelSDK {
         input_len: u32,
         return_offset: *mut u8,
         return_len: u32,
-        fuel_offset: *const u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> i32 {
         let bytecode_hash32 = unsafe { &*ptr::slice_from_raw_parts(bytecode_hash32_offset, 32) };
         let input =
             unsafe { &*ptr::slice_from_raw_parts(input_offset, input_len as usize) }.to_vec();
-        let fuel = LittleEndian::read_u32(unsafe {
-            &*ptr::slice_from_raw_parts(fuel_offset as *const u8, 4)
-        });
-        match with_context_mut(move |ctx| {
+        let mut fuel = unsafe { *fuel_offset };
+        let result = with_context_mut(|ctx| {
             SysExecHash::fn_impl(
                 ctx,
                 bytecode_hash32.try_into().unwrap(),
-                input.clone(),
+                input,
                 return_len,
-                fuel,
+                &mut fuel,
                 state,
             )
-        }) {
-            Ok((result, remaining_fuel)) => {
+        });
+        unsafe { *fuel_offset = fuel };
+        match result {
+            Ok(result) => {
                 if return_len > 0 {
                     unsafe { ptr::copy(result.as_ptr(), return_offset, return_len as usize) }
                 }
-                LittleEndian::write_u32(
-                    unsafe { &mut *ptr::slice_from_raw_parts_mut(fuel_offset as *mut u8, 4) },
-                    remaining_fuel,
-                );
                 0
             }
             Err(err) => err,
@@ -230,6 +218,10 @@ impl LowLevelAPI for LowLevelSDK {
         with_context(|ctx| SysState::fn_impl(ctx))
     }
 
+    fn sys_fuel(_delta: u64) {
+        // native execution isn't metered, fuel is charged by the runtime only
+    }
+
     fn jzkt_open(root32_ptr: *const u8) {
         let root = unsafe { &*ptr::slice_from_raw_parts(root32_ptr, 32) };
         with_context_mut(|ctx| JzktOpen::fn_impl(ctx, root).unwrap());
@@ -285,7 +277,7 @@ impl LowLevelAPI for LowLevelSDK {
             .map(|v| B256::new(*v))
             .collect::<Vec<_>>();
         let data = unsafe { &*ptr::slice_from_raw_parts(data_ptr, data_len as usize) };
-        with_context_mut(|ctx| JzktEmitLog::fn_impl(ctx, key, &topics, data));
+        with_context_mut(|ctx| JzktEmitLog::fn_impl(ctx, key, &topics, data).unwrap());
     }
     fn jzkt_commit(root32_offset: *mut u8) {
         let root = with_context_mut(|ctx| JzktCommit::fn_impl(ctx).unwrap());
@@ -294,6 +286,23 @@ impl LowLevelAPI for LowLevelSDK {
     fn jzkt_rollback(checkpoint: u64) {
         with_context_mut(|ctx| JzktRollback::fn_impl(ctx, JournalCheckpoint::from_u64(checkpoint)));
     }
+    fn jzkt_store(address32_ptr: *const u8, slot32_ptr: *const u8, value32_ptr: *const u8) {
+        let address = unsafe { &*ptr::slice_from_raw_parts(address32_ptr, 32) };
+        let slot = unsafe { &*ptr::slice_from_raw_parts(slot32_ptr, 32) };
+        let value = unsafe { &*ptr::slice_from_raw_parts(value32_ptr, 32) };
+        with_context_mut(|ctx| JzktStore::fn_impl(ctx, address, slot, value).unwrap());
+    }
+    fn jzkt_load(address32_ptr: *const u8, slot32_ptr: *const u8, value32_ptr: *mut u8) -> bool {
+        let address = unsafe { &*ptr::slice_from_raw_parts(address32_ptr, 32) };
+        let slot = unsafe { &*ptr::slice_from_raw_parts(slot32_ptr, 32) };
+        match with_context_mut(|ctx| JzktLoad::fn_impl(ctx, address, slot)) {
+            Some((value, is_cold)) => {
+                unsafe { ptr::copy(value.as_ptr(), value32_ptr, 32) }
+                is_cold
+            }
+            None => true,
+        }
+    }
     fn jzkt_preimage_size(key32_ptr: *const u8) -> u32 {
         let key = unsafe { &*ptr::slice_from_raw_parts(key32_ptr, 32) };
         return with_context_mut(|ctx| JzktPreimageSize::fn_impl(ctx, key).unwrap());
@@ -305,6 +314,13 @@ impl LowLevelAPI for LowLevelSDK {
             unsafe { &mut *ptr::slice_from_raw_parts_mut(preimage_ptr, preimage_copy.len()) };
         dest.copy_from_slice(&preimage_copy);
     }
+    fn jzkt_preimage_read(key32_ptr: *const u8, target: *mut u8, offset: u32, length: u32) {
+        let key = unsafe { &*ptr::slice_from_raw_parts(key32_ptr, 32) };
+        let preimage =
+            with_context_mut(|ctx| JzktPreimageRead::fn_impl(ctx, key, offset, length).unwrap());
+        let dest = unsafe { &mut *ptr::slice_from_raw_parts_mut(target, preimage.len()) };
+        dest.copy_from_slice(&preimage);
+    }
 }
 
 impl LowLevelSDK {
@@ -349,4 +365,16 @@ impl LowLevelSDK {
             ctx.set(ctx2);
         });
     }
+
+    pub fn with_fuel_schedule(fuel_schedule: Rc<FuelSchedule>) {
+        CONTEXT.with(|ctx| {
+            let mut ctx2 = ctx.take();
+            ctx2.with_fuel_schedule(fuel_schedule);
+            ctx.set(ctx2);
+        });
+    }
+
+    pub fn get_jzkt() -> Option<Rc<RefCell<dyn IJournaledTrie>>> {
+        with_context_mut(|ctx| ctx.jzkt())
+    }
 }
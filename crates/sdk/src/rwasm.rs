@@ -9,16 +9,20 @@ use crate::{
         _jzkt_compute_root,
         _jzkt_emit_log,
         _jzkt_get,
+        _jzkt_load,
         _jzkt_open,
         _jzkt_preimage_copy,
+        _jzkt_preimage_read,
         _jzkt_preimage_size,
         _jzkt_remove,
         _jzkt_rollback,
+        _jzkt_store,
         _jzkt_update,
         _jzkt_update_preimage,
         _sys_exec,
         _sys_exec_hash,
         _sys_forward_output,
+        _sys_fuel,
         _sys_halt,
         _sys_input_size,
         _sys_output_size,
@@ -72,6 +76,11 @@ impl LowLevelAPI for LowLevelSDK {
         unsafe { _sys_state() }
     }
 
+    #[inline(always)]
+    fn sys_fuel(delta: u64) {
+        unsafe { _sys_fuel(delta) }
+    }
+
     #[inline(always)]
     fn sys_exec(
         code_offset: *const u8,
@@ -80,7 +89,7 @@ impl LowLevelAPI for LowLevelSDK {
         input_len: u32,
         return_offset: *mut u8,
         return_len: u32,
-        fuel_offset: *const u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> i32 {
         unsafe {
@@ -104,7 +113,7 @@ impl LowLevelAPI for LowLevelSDK {
         input_len: u32,
         return_offset: *mut u8,
         return_len: u32,
-        fuel_offset: *const u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> i32 {
         unsafe {
@@ -204,6 +213,14 @@ impl LowLevelAPI for LowLevelSDK {
         unsafe { _jzkt_rollback(checkpoint) }
     }
     #[inline(always)]
+    fn jzkt_store(address32_ptr: *const u8, slot32_ptr: *const u8, value32_ptr: *const u8) {
+        unsafe { _jzkt_store(address32_ptr, slot32_ptr, value32_ptr) }
+    }
+    #[inline(always)]
+    fn jzkt_load(address32_ptr: *const u8, slot32_ptr: *const u8, value32_ptr: *mut u8) -> bool {
+        unsafe { _jzkt_load(address32_ptr, slot32_ptr, value32_ptr) }
+    }
+    #[inline(always)]
     fn jzkt_preimage_size(hash32_ptr: *const u8) -> u32 {
         unsafe { _jzkt_preimage_size(hash32_ptr) }
     }
@@ -211,4 +228,8 @@ impl LowLevelAPI for LowLevelSDK {
     fn jzkt_preimage_copy(hash32_ptr: *const u8, preimage_ptr: *mut u8) {
         unsafe { _jzkt_preimage_copy(hash32_ptr, preimage_ptr) }
     }
+    #[inline(always)]
+    fn jzkt_preimage_read(hash32_ptr: *const u8, target: *mut u8, offset: u32, length: u32) {
+        unsafe { _jzkt_preimage_read(hash32_ptr, target, offset, length) }
+    }
 }
@@ -22,6 +22,7 @@ pub trait LowLevelAPI {
     fn sys_output_size() -> u32;
     fn sys_read_output(target: *mut u8, offset: u32, length: u32);
     fn sys_state() -> u32;
+    fn sys_fuel(delta: u64);
     fn sys_exec(
         code_offset: *const u8,
         code_len: u32,
@@ -29,7 +30,7 @@ pub trait LowLevelAPI {
         input_len: u32,
         return_offset: *mut u8,
         return_len: u32,
-        fuel_offset: *const u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> i32;
 
@@ -39,7 +40,7 @@ pub trait LowLevelAPI {
         input_len: u32,
         return_offset: *mut u8,
         return_len: u32,
-        fuel_offset: *const u32,
+        fuel_offset: *mut u64,
         state: u32,
     ) -> i32;
 
@@ -64,6 +65,9 @@ pub trait LowLevelAPI {
     );
     fn jzkt_commit(root32_offset: *mut u8);
     fn jzkt_rollback(checkpoint: u64);
+    fn jzkt_store(address32_ptr: *const u8, slot32_ptr: *const u8, value32_ptr: *const u8);
+    fn jzkt_load(address32_ptr: *const u8, slot32_ptr: *const u8, value32_ptr: *mut u8) -> bool;
     fn jzkt_preimage_size(hash32_ptr: *const u8) -> u32;
     fn jzkt_preimage_copy(hash32_ptr: *const u8, preimage_ptr: *mut u8);
+    fn jzkt_preimage_read(hash32_ptr: *const u8, target: *mut u8, offset: u32, length: u32);
 }
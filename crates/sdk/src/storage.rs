@@ -0,0 +1,425 @@
+use crate::{evm::ExecutionContext, LowLevelAPI, LowLevelSDK};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use fluentbase_types::{Address, Bytes, B256, U256};
+
+const DOMAIN: [u8; 32] = [0u8; 32];
+
+/// Computes a JZKT storage key for the contract's slot, the formula is `p(address, p(slot_0,
+/// slot_1))` (the same as `JournaledTrie::storage_key`)
+pub fn storage_key(address: &Address, slot: &[u8; 32]) -> [u8; 32] {
+    let mut slot0: [u8; 32] = [0u8; 32];
+    let mut slot1: [u8; 32] = [0u8; 32];
+    // split slot32 into two 16 byte values (slot is always 32 bytes)
+    slot0[0..16].copy_from_slice(&slot[0..16]);
+    slot1[0..16].copy_from_slice(&slot[16..32]);
+    // pad address to 32 bytes value
+    let mut address32: [u8; 32] = [0u8; 32];
+    address32[12..].copy_from_slice(address.as_slice());
+    let mut storage_key: [u8; 32] = [0u8; 32];
+    LowLevelSDK::crypto_poseidon2(
+        slot0.as_ptr(),
+        slot1.as_ptr(),
+        DOMAIN.as_ptr(),
+        storage_key.as_mut_ptr(),
+    );
+    LowLevelSDK::crypto_poseidon2(
+        address32.as_ptr(),
+        storage_key.as_ptr(),
+        DOMAIN.as_ptr(),
+        storage_key.as_mut_ptr(),
+    );
+    storage_key
+}
+
+/// Reads a raw 32-byte word from the current contract's storage slot.
+///
+/// Slots are passed to the trie in little-endian, the same way as EVM's `SLOAD` does it.
+pub fn sload(slot: &U256) -> [u8; 32] {
+    let address32 = ExecutionContext::contract_address().into_word();
+    let slot32 = slot.to_le_bytes::<32>();
+    let mut value = [0u8; 32];
+    LowLevelSDK::jzkt_load(address32.as_ptr(), slot32.as_ptr(), value.as_mut_ptr());
+    value
+}
+
+/// Writes a raw 32-byte word into the current contract's storage slot.
+pub fn sstore(slot: &U256, value: &[u8; 32]) {
+    let address32 = ExecutionContext::contract_address().into_word();
+    let slot32 = slot.to_le_bytes::<32>();
+    LowLevelSDK::jzkt_store(address32.as_ptr(), slot32.as_ptr(), value.as_ptr());
+}
+
+/// A type that fits into one storage word.
+///
+/// Values are stored as `U256` in little-endian, so they can be read by EVM's `SLOAD` as well.
+pub trait StorageWord: Sized {
+    fn to_word(&self) -> U256;
+    fn from_word(word: U256) -> Self;
+}
+
+impl StorageWord for U256 {
+    fn to_word(&self) -> U256 {
+        *self
+    }
+    fn from_word(word: U256) -> Self {
+        word
+    }
+}
+
+impl StorageWord for B256 {
+    fn to_word(&self) -> U256 {
+        U256::from_be_bytes(self.0)
+    }
+    fn from_word(word: U256) -> Self {
+        B256::new(word.to_be_bytes::<32>())
+    }
+}
+
+impl StorageWord for Address {
+    fn to_word(&self) -> U256 {
+        U256::from_be_slice(self.as_slice())
+    }
+    fn from_word(word: U256) -> Self {
+        Address::from_word(B256::new(word.to_be_bytes::<32>()))
+    }
+}
+
+impl StorageWord for bool {
+    fn to_word(&self) -> U256 {
+        U256::from(*self as u8)
+    }
+    fn from_word(word: U256) -> Self {
+        word != U256::ZERO
+    }
+}
+
+macro_rules! impl_storage_word_uint {
+    ($($typ:ty),*) => {
+        $(
+            impl StorageWord for $typ {
+                fn to_word(&self) -> U256 {
+                    U256::from(*self)
+                }
+                fn from_word(word: U256) -> Self {
+                    <$typ>::try_from(word).expect("storage word doesn't fit into the integer type")
+                }
+            }
+        )*
+    };
+}
+impl_storage_word_uint!(u8, u16, u32, u64);
+
+/// A type that can be used as a key of the storage mapping.
+///
+/// Value types are padded to 32 bytes while byte arrays are used as is, it matches Solidity's
+/// rules of mapping slot calculation.
+pub trait StorageMapKey {
+    fn write_key(&self, buffer: &mut Vec<u8>);
+}
+
+impl<T: StorageWord> StorageMapKey for T {
+    fn write_key(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(&self.to_word().to_be_bytes::<32>());
+    }
+}
+
+impl StorageMapKey for Bytes {
+    fn write_key(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(self.as_ref());
+    }
+}
+
+impl StorageMapKey for [u8] {
+    fn write_key(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(self);
+    }
+}
+
+impl StorageMapKey for str {
+    fn write_key(&self, buffer: &mut Vec<u8>) {
+        buffer.extend_from_slice(self.as_bytes());
+    }
+}
+
+/// Describes how slots of dynamic storage types (mappings and arrays) are derived.
+pub trait StorageLayout {
+    fn mapping_slot(slot: &U256, key: &[u8]) -> U256;
+    fn array_slot(slot: &U256) -> U256;
+}
+
+/// Solidity compatible layout: `keccak256(key . slot)` for mappings and `keccak256(slot)` for
+/// arrays.
+pub struct SolidityLayout;
+
+impl StorageLayout for SolidityLayout {
+    fn mapping_slot(slot: &U256, key: &[u8]) -> U256 {
+        let mut raw_storage_key = Vec::with_capacity(key.len() + 32);
+        raw_storage_key.extend_from_slice(key);
+        raw_storage_key.extend_from_slice(&slot.to_be_bytes::<32>());
+        let mut result = [0u8; 32];
+        LowLevelSDK::crypto_keccak256(
+            raw_storage_key.as_ptr(),
+            raw_storage_key.len() as u32,
+            result.as_mut_ptr(),
+        );
+        U256::from_be_bytes(result)
+    }
+
+    fn array_slot(slot: &U256) -> U256 {
+        let slot = slot.to_be_bytes::<32>();
+        let mut result = [0u8; 32];
+        LowLevelSDK::crypto_keccak256(slot.as_ptr(), slot.len() as u32, result.as_mut_ptr());
+        U256::from_be_bytes(result)
+    }
+}
+
+/// Layout that uses Poseidon hashing instead of Keccak256, it's cheaper to prove but is not
+/// compatible with Solidity contracts.
+pub struct PoseidonLayout;
+
+impl StorageLayout for PoseidonLayout {
+    fn mapping_slot(slot: &U256, key: &[u8]) -> U256 {
+        let mut raw_storage_key = Vec::with_capacity(key.len() + 32);
+        raw_storage_key.extend_from_slice(key);
+        raw_storage_key.extend_from_slice(&slot.to_be_bytes::<32>());
+        let mut result = [0u8; 32];
+        LowLevelSDK::crypto_poseidon(
+            raw_storage_key.as_ptr(),
+            raw_storage_key.len() as u32,
+            result.as_mut_ptr(),
+        );
+        U256::from_be_bytes(result)
+    }
+
+    fn array_slot(slot: &U256) -> U256 {
+        let slot = slot.to_be_bytes::<32>();
+        let mut result = [0u8; 32];
+        LowLevelSDK::crypto_poseidon(slot.as_ptr(), slot.len() as u32, result.as_mut_ptr());
+        U256::from_be_bytes(result)
+    }
+}
+
+/// A single value stored in the storage slot.
+pub struct StorageValue<T> {
+    slot: U256,
+    _phantom: PhantomData<T>,
+}
+
+impl<T: StorageWord> StorageValue<T> {
+    pub const fn new(slot: U256) -> Self {
+        Self {
+            slot,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn slot(&self) -> U256 {
+        self.slot
+    }
+
+    pub fn get(&self) -> T {
+        T::from_word(U256::from_le_bytes(sload(&self.slot)))
+    }
+
+    pub fn set(&self, value: T) {
+        sstore(&self.slot, &value.to_word().to_le_bytes::<32>());
+    }
+
+    pub fn clear(&self) {
+        sstore(&self.slot, &[0u8; 32]);
+    }
+}
+
+/// A mapping from `K` into `V`, elements are stored in `L::mapping_slot(slot, key)` slots.
+pub struct StorageMap<K: ?Sized, V, L = SolidityLayout> {
+    slot: U256,
+    _key: PhantomData<fn(&K)>,
+    _phantom: PhantomData<(V, L)>,
+}
+
+impl<K: StorageMapKey + ?Sized, V: StorageWord, L: StorageLayout> StorageMap<K, V, L> {
+    pub const fn new(slot: U256) -> Self {
+        Self {
+            slot,
+            _key: PhantomData,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn entry(&self, key: &K) -> StorageValue<V> {
+        let mut raw_key = Vec::new();
+        key.write_key(&mut raw_key);
+        StorageValue::new(L::mapping_slot(&self.slot, &raw_key))
+    }
+
+    pub fn get(&self, key: &K) -> V {
+        self.entry(key).get()
+    }
+
+    pub fn set(&self, key: &K, value: V) {
+        self.entry(key).set(value)
+    }
+
+    pub fn remove(&self, key: &K) {
+        self.entry(key).clear()
+    }
+}
+
+/// A dynamic array, its length is stored in the `slot` and the elements are stored starting
+/// from `L::array_slot(slot)`.
+pub struct StorageVec<T, L = SolidityLayout> {
+    slot: U256,
+    _phantom: PhantomData<(T, L)>,
+}
+
+impl<T: StorageWord, L: StorageLayout> StorageVec<T, L> {
+    pub const fn new(slot: U256) -> Self {
+        Self {
+            slot,
+            _phantom: PhantomData,
+        }
+    }
+
+    pub fn len(&self) -> u64 {
+        StorageValue::<u64>::new(self.slot).get()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn element(&self, index: u64) -> StorageValue<T> {
+        StorageValue::new(L::array_slot(&self.slot) + U256::from(index))
+    }
+
+    pub fn get(&self, index: u64) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+        Some(self.element(index).get())
+    }
+
+    pub fn set(&self, index: u64, value: T) {
+        let len = self.len();
+        if index >= len {
+            panic!("storage index out of bounds: {} >= {}", index, len);
+        }
+        self.element(index).set(value)
+    }
+
+    pub fn push(&self, value: T) {
+        let len = self.len();
+        self.element(len).set(value);
+        StorageValue::<u64>::new(self.slot).set(len + 1);
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        let len = self.len();
+        if len == 0 {
+            return None;
+        }
+        let element = self.element(len - 1);
+        let value = element.get();
+        element.clear();
+        StorageValue::<u64>::new(self.slot).set(len - 1);
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evm::ContractInput;
+    use alloc::rc::Rc;
+    use core::cell::RefCell;
+    use fluentbase_codec::Encoder;
+    use fluentbase_runtime::{
+        types::InMemoryTrieDb,
+        zktrie::ZkTrieStateDb,
+        IJournaledTrie,
+        JournaledTrie,
+    };
+    use fluentbase_types::address;
+
+    const ADDRESS: Address = address!("0000000000000000000000000000000000000001");
+
+    fn with_test_contract() -> Rc<RefCell<dyn IJournaledTrie>> {
+        let contract_input = ContractInput {
+            contract_address: ADDRESS,
+            ..Default::default()
+        };
+        LowLevelSDK::with_test_input(contract_input.encode_to_vec(0));
+        LowLevelSDK::with_default_jzkt()
+    }
+
+    #[test]
+    fn test_storage_key() {
+        let slot = [7u8; 32];
+        assert_eq!(
+            storage_key(&ADDRESS, &slot),
+            JournaledTrie::<ZkTrieStateDb<InMemoryTrieDb>>::storage_key(&ADDRESS, &slot)
+        );
+    }
+
+    #[test]
+    fn test_storage_value() {
+        let jzkt = with_test_contract();
+        let value = StorageValue::<U256>::new(U256::from(1));
+        assert_eq!(value.get(), U256::ZERO);
+        value.set(U256::from(100));
+        assert_eq!(value.get(), U256::from(100));
+        // value must be visible for EVM's SLOAD
+        let (raw_value, _) = jzkt
+            .borrow_mut()
+            .load(&ADDRESS, &U256::from(1).to_le_bytes::<32>())
+            .unwrap();
+        assert_eq!(raw_value, U256::from(100).to_le_bytes::<32>());
+    }
+
+    #[test]
+    fn test_storage_map() {
+        with_test_contract();
+        let balances = StorageMap::<Address, U256>::new(U256::ZERO);
+        let alice = address!("1000000000000000000000000000000000000001");
+        let bob = address!("1000000000000000000000000000000000000002");
+        balances.set(&alice, U256::from(10));
+        balances.set(&bob, U256::from(20));
+        assert_eq!(balances.get(&alice), U256::from(10));
+        assert_eq!(balances.get(&bob), U256::from(20));
+        balances.remove(&alice);
+        assert_eq!(balances.get(&alice), U256::ZERO);
+        // mapping slot is `keccak256(pad32(key) . pad32(slot))`
+        let mut raw_key = [0u8; 64];
+        raw_key[12..32].copy_from_slice(bob.as_slice());
+        let mut expected_slot = [0u8; 32];
+        LowLevelSDK::crypto_keccak256(raw_key.as_ptr(), 64, expected_slot.as_mut_ptr());
+        assert_eq!(
+            balances.entry(&bob).slot(),
+            U256::from_be_bytes(expected_slot)
+        );
+    }
+
+    #[test]
+    fn test_storage_vec() {
+        with_test_contract();
+        let values = StorageVec::<u64>::new(U256::from(2));
+        assert!(values.is_empty());
+        values.push(1);
+        values.push(2);
+        values.push(3);
+        assert_eq!(values.len(), 3);
+        assert_eq!(values.get(1), Some(2));
+        assert_eq!(values.get(3), None);
+        values.set(1, 5);
+        assert_eq!(values.get(1), Some(5));
+        assert_eq!(values.pop(), Some(3));
+        assert_eq!(values.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "storage word doesn't fit into the integer type")]
+    fn test_storage_word_overflow() {
+        u8::from_word(U256::from(256));
+    }
+}
@@ -1,2 +1,43 @@
+use crate::{address, Address};
+
+/// Every WASM binary starts with `\0asm`
+pub const WASM_MAGIC: [u8; 4] = [0x00, 0x61, 0x73, 0x6d];
+
 pub const STATE_MAIN: u32 = 0;
 pub const STATE_DEPLOY: u32 = 1;
+/// Flag of the `_sys_exec`/`_sys_exec_hash` state that executes the callee in static mode, it
+/// means that all state modifications are forbidden for the callee and its nested calls
+pub const STATE_STATIC_FLAG: u32 = 0x8000_0000;
+
+/// EVM call stack limit, it's shared by EVM and WASM frames
+pub const CALL_STACK_LIMIT: u64 = 1024;
+
+/// Number of account fields stored in the journaled trie
+pub const JZKT_ACCOUNT_FIELDS_COUNT: u32 = 6;
+
+pub const JZKT_ACCOUNT_BALANCE_FIELD: u32 = 0;
+pub const JZKT_ACCOUNT_NONCE_FIELD: u32 = 1;
+pub const JZKT_ACCOUNT_SOURCE_BYTECODE_SIZE_FIELD: u32 = 2;
+pub const JZKT_ACCOUNT_SOURCE_BYTECODE_HASH_FIELD: u32 = 3;
+pub const JZKT_ACCOUNT_RWASM_BYTECODE_SIZE_FIELD: u32 = 4;
+pub const JZKT_ACCOUNT_RWASM_BYTECODE_HASH_FIELD: u32 = 5;
+
+/// Compression flags for upper fields.
+///
+/// We compress following fields:
+/// - balance (0) because of balance overflow
+/// - source code hash (3) because its keccak256
+///
+/// Mask is: 0b00001001
+pub const JZKT_COMPRESSION_FLAGS: u32 =
+    (1 << JZKT_ACCOUNT_BALANCE_FIELD) + (1 << JZKT_ACCOUNT_SOURCE_BYTECODE_HASH_FIELD);
+
+/// Number of fields of the trie entry with immutable data returned by the WASM deploy function,
+/// the entry is stored next to the account under `keccak256(address ++ "immutable_data")`
+pub const JZKT_IMMUTABLE_DATA_FIELDS_COUNT: u32 = 2;
+
+pub const JZKT_IMMUTABLE_DATA_SIZE_FIELD: u32 = 0;
+pub const JZKT_IMMUTABLE_DATA_HASH_FIELD: u32 = 1;
+
+pub const ECL_CONTRACT_ADDRESS: Address = address!("5200000000000000000000000000000000000001");
+pub const WCL_CONTRACT_ADDRESS: Address = address!("5200000000000000000000000000000000000002");
@@ -0,0 +1,113 @@
+use crate::SysFuncIdx;
+use hashbrown::HashMap;
+
+/// Default amount of rWASM fuel that is equal to one unit of EVM gas.
+///
+/// rWASM engine charges fuel for every executed instruction (one unit for most of the
+/// instructions, bulk memory and table operations are charged per element), host functions are
+/// charged additionally according to [`FuelSchedule::sys_func_fuel`].
+pub const FUEL_PER_GAS: u64 = 1;
+
+/// Converts gas limit into fuel limit using the default ratio, the result is saturated on
+/// overflow
+#[inline(always)]
+pub const fn gas_to_fuel(gas: u64) -> u64 {
+    gas.saturating_mul(FUEL_PER_GAS)
+}
+
+/// Converts consumed fuel into gas using the default ratio, partially consumed unit of gas is
+/// rounded up
+#[inline(always)]
+pub const fn fuel_to_gas(fuel: u64) -> u64 {
+    fuel.div_ceil(FUEL_PER_GAS)
+}
+
+/// Cost of the host function call, it's `base + word * ceil(len / 32)` where `len` is the size of
+/// the data processed by the function
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SysFuncCost {
+    pub base: u64,
+    pub word: u64,
+}
+
+impl SysFuncCost {
+    pub const fn new(base: u64, word: u64) -> Self {
+        Self { base, word }
+    }
+
+    /// Returns cost of the data of the given size (w/o base cost)
+    pub const fn word_cost(&self, len: u64) -> u64 {
+        self.word.saturating_mul(len.div_ceil(32))
+    }
+
+    /// Returns total cost of the call that processes the data of the given size
+    pub const fn total_cost(&self, len: u64) -> u64 {
+        self.base.saturating_add(self.word_cost(len))
+    }
+}
+
+/// Gas schedule of the rWASM execution, it's accepted by the runtime (host function costs) and by
+/// revm (conversion of gas limits into fuel limits).
+///
+/// Every executed instruction costs one unit of fuel, so `fuel_per_gas` is the number of
+/// instructions that are equal to one unit of gas. Per-instruction weights aren't supported yet,
+/// the rWASM engine charges a fixed amount of fuel for every instruction, so only the costs of host
+/// functions can be configured by the schedule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuelSchedule {
+    /// Amount of fuel that is equal to one unit of gas, zero is treated as the default ratio
+    pub fuel_per_gas: u64,
+    /// Gas costs of host functions that override [`SysFuncIdx::gas_cost`]
+    pub sys_func_gas_costs: HashMap<SysFuncIdx, SysFuncCost>,
+}
+
+impl Default for FuelSchedule {
+    fn default() -> Self {
+        Self {
+            fuel_per_gas: FUEL_PER_GAS,
+            sys_func_gas_costs: HashMap::new(),
+        }
+    }
+}
+
+impl FuelSchedule {
+    pub fn with_fuel_per_gas(mut self, fuel_per_gas: u64) -> Self {
+        self.fuel_per_gas = fuel_per_gas;
+        self
+    }
+
+    pub fn with_sys_func_gas_cost(mut self, sys_func: SysFuncIdx, cost: SysFuncCost) -> Self {
+        self.sys_func_gas_costs.insert(sys_func, cost);
+        self
+    }
+
+    pub fn fuel_per_gas(&self) -> u64 {
+        match self.fuel_per_gas {
+            0 => FUEL_PER_GAS,
+            fuel_per_gas => fuel_per_gas,
+        }
+    }
+
+    /// Converts gas limit into fuel limit, the result is saturated on overflow
+    pub fn gas_to_fuel(&self, gas: u64) -> u64 {
+        gas.saturating_mul(self.fuel_per_gas())
+    }
+
+    /// Converts consumed fuel into gas, partially consumed unit of gas is rounded up
+    pub fn fuel_to_gas(&self, fuel: u64) -> u64 {
+        fuel.div_ceil(self.fuel_per_gas())
+    }
+
+    /// Returns fuel cost of the host function
+    pub fn sys_func_fuel(&self, sys_func: SysFuncIdx) -> SysFuncCost {
+        let gas_cost = self
+            .sys_func_gas_costs
+            .get(&sys_func)
+            .copied()
+            .unwrap_or_else(|| sys_func.gas_cost());
+        SysFuncCost::new(
+            self.gas_to_fuel(gas_cost.base),
+            self.gas_to_fuel(gas_cost.word),
+        )
+    }
+}
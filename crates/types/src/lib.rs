@@ -2,10 +2,12 @@
 #![allow(dead_code, unreachable_patterns, unused_macros)]
 
 pub use consts::*;
+pub use fuel::*;
 pub use linker::*;
 pub use types::*;
 
 mod consts;
+mod fuel;
 mod linker;
 mod types;
 
@@ -9,7 +9,10 @@ macro_rules! import_func {
     };
 }
 
-const SHARED_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 19] = [
+/// Imports of shared applications, they can read the trie, but can't modify it. State changes
+/// (`_jzkt_update`, `_jzkt_store`, `_jzkt_commit` etc.) are available for sovereign applications
+/// only, so their handlers aren't registered by the shared runtime either.
+const SHARED_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 22] = [
     import_func!("_crypto_keccak256", CRYPTO_KECCAK256),
     import_func!("_crypto_poseidon", CRYPTO_KECCAK256),
     import_func!("_crypto_poseidon2", CRYPTO_POSEIDON2),
@@ -24,6 +27,7 @@ const SHARED_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 19] = [
     import_func!("_sys_state", SYS_STATE),
     import_func!("_sys_exec", SYS_EXEC),
     import_func!("_sys_exec_hash", SYS_EXEC_HASH),
+    import_func!("_sys_fuel", SYS_FUEL),
     // import_func!("_jzkt_open", JZKT_OPEN),
     // import_func!("_jzkt_checkpoint", JZKT_CHECKPOINT),
     import_func!("_jzkt_get", JZKT_GET),
@@ -34,15 +38,19 @@ const SHARED_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 19] = [
     import_func!("_jzkt_emit_log", JZKT_EMIT_LOG),
     // import_func!("_jzkt_commit", JZKT_COMMIT),
     // import_func!("_jzkt_rollback", JZKT_ROLLBACK),
+    // storage writes are sovereign only like other state changes
+    // import_func!("_jzkt_store", JZKT_STORE),
+    import_func!("_jzkt_load", JZKT_LOAD),
     import_func!("_jzkt_preimage_size", JZKT_PREIMAGE_SIZE),
     import_func!("_jzkt_preimage_copy", JZKT_PREIMAGE_COPY),
+    import_func!("_jzkt_preimage_read", JZKT_PREIMAGE_READ),
 ];
 
-pub fn create_shared_import_linker<F: From<[(&'static str, &'static str, u32, u32); 19]>>() -> F {
+pub fn create_shared_import_linker<F: From<[(&'static str, &'static str, u32, u32); 22]>>() -> F {
     F::from(SHARED_IMPORT_LINKER)
 }
 
-const SOVEREIGN_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 26] = [
+const SOVEREIGN_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 30] = [
     import_func!("_crypto_keccak256", CRYPTO_KECCAK256),
     import_func!("_crypto_poseidon", CRYPTO_KECCAK256),
     import_func!("_crypto_poseidon2", CRYPTO_POSEIDON2),
@@ -57,6 +65,7 @@ const SOVEREIGN_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 26] = [
     import_func!("_sys_state", SYS_STATE),
     import_func!("_sys_exec", SYS_EXEC),
     import_func!("_sys_exec_hash", SYS_EXEC_HASH),
+    import_func!("_sys_fuel", SYS_FUEL),
     import_func!("_jzkt_open", JZKT_OPEN),
     import_func!("_jzkt_checkpoint", JZKT_CHECKPOINT),
     import_func!("_jzkt_get", JZKT_GET),
@@ -67,11 +76,14 @@ const SOVEREIGN_IMPORT_LINKER: [(&'static str, &'static str, u32, u32); 26] = [
     import_func!("_jzkt_emit_log", JZKT_EMIT_LOG),
     import_func!("_jzkt_commit", JZKT_COMMIT),
     import_func!("_jzkt_rollback", JZKT_ROLLBACK),
+    import_func!("_jzkt_store", JZKT_STORE),
+    import_func!("_jzkt_load", JZKT_LOAD),
     import_func!("_jzkt_preimage_size", JZKT_PREIMAGE_SIZE),
     import_func!("_jzkt_preimage_copy", JZKT_PREIMAGE_COPY),
+    import_func!("_jzkt_preimage_read", JZKT_PREIMAGE_READ),
 ];
 
-pub fn create_sovereign_import_linker<F: From<[(&'static str, &'static str, u32, u32); 26]>>() -> F
+pub fn create_sovereign_import_linker<F: From<[(&'static str, &'static str, u32, u32); 30]>>() -> F
 {
     F::from(SOVEREIGN_IMPORT_LINKER)
 }
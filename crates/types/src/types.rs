@@ -1,3 +1,4 @@
+use crate::SysFuncCost;
 use rwasm::{
     core::{Trap, TrapCode},
     engine::bytecode::FuncIdx,
@@ -124,6 +125,7 @@ pub enum SysFuncIdx {
     SYS_EXEC = 0x0008,
     SYS_EXEC_HASH = 0x0009,
     SYS_FORWARD_OUTPUT = 0x000a,
+    SYS_FUEL = 0x000b,
 
     // jzkt
     JZKT_OPEN = 0x0701,
@@ -136,29 +138,52 @@ pub enum SysFuncIdx {
     JZKT_EMIT_LOG = 0x0708,
     JZKT_COMMIT = 0x0709,
     JZKT_ROLLBACK = 0x070A,
+    JZKT_STORE = 0x070B,
+    JZKT_LOAD = 0x070C,
     JZKT_PREIMAGE_SIZE = 0x070D,
     JZKT_PREIMAGE_COPY = 0x070E,
+    JZKT_PREIMAGE_READ = 0x070F,
 }
 
 impl SysFuncIdx {
-    pub fn fuel_cost(&self) -> u32 {
+    /// Returns default gas cost of the host function, prices are similar to the prices of the EVM
+    /// opcodes that do the same job (e.g. `KECCAK256`, `SLOAD`, `LOG` or `CALL`)
+    pub fn gas_cost(&self) -> SysFuncCost {
         match self {
-            SysFuncIdx::SYS_HALT => 1,
-            SysFuncIdx::SYS_STATE => 1,
-            SysFuncIdx::SYS_READ => 1,
-            SysFuncIdx::SYS_INPUT_SIZE => 1,
-            SysFuncIdx::SYS_WRITE => 1,
-            SysFuncIdx::CRYPTO_KECCAK256 => 1,
-            SysFuncIdx::CRYPTO_POSEIDON => 1,
-            SysFuncIdx::CRYPTO_POSEIDON2 => 1,
-            SysFuncIdx::CRYPTO_ECRECOVER => 1,
-            SysFuncIdx::JZKT_OPEN => 1,
-            SysFuncIdx::JZKT_UPDATE => 1,
-            SysFuncIdx::JZKT_GET => 1,
-            SysFuncIdx::JZKT_COMPUTE_ROOT => 1,
-            SysFuncIdx::JZKT_ROLLBACK => 1,
-            SysFuncIdx::JZKT_COMMIT => 1,
-            _ => 1, //unreachable!("not configured fuel for opcode: {:?}", self),
+            SysFuncIdx::UNKNOWN => SysFuncCost::new(0, 0),
+            // crypto
+            SysFuncIdx::CRYPTO_KECCAK256 => SysFuncCost::new(30, 6),
+            SysFuncIdx::CRYPTO_POSEIDON => SysFuncCost::new(30, 6),
+            SysFuncIdx::CRYPTO_POSEIDON2 => SysFuncCost::new(30, 0),
+            SysFuncIdx::CRYPTO_ECRECOVER => SysFuncCost::new(3000, 0),
+            // sys
+            SysFuncIdx::SYS_HALT => SysFuncCost::new(0, 0),
+            SysFuncIdx::SYS_STATE => SysFuncCost::new(2, 0),
+            SysFuncIdx::SYS_INPUT_SIZE => SysFuncCost::new(2, 0),
+            SysFuncIdx::SYS_OUTPUT_SIZE => SysFuncCost::new(2, 0),
+            SysFuncIdx::SYS_READ => SysFuncCost::new(3, 3),
+            SysFuncIdx::SYS_WRITE => SysFuncCost::new(3, 3),
+            SysFuncIdx::SYS_READ_OUTPUT => SysFuncCost::new(3, 3),
+            SysFuncIdx::SYS_FORWARD_OUTPUT => SysFuncCost::new(3, 3),
+            SysFuncIdx::SYS_EXEC => SysFuncCost::new(700, 3),
+            SysFuncIdx::SYS_EXEC_HASH => SysFuncCost::new(700, 3),
+            SysFuncIdx::SYS_FUEL => SysFuncCost::new(2, 0),
+            // jzkt
+            SysFuncIdx::JZKT_OPEN => SysFuncCost::new(100, 0),
+            SysFuncIdx::JZKT_CHECKPOINT => SysFuncCost::new(2, 0),
+            SysFuncIdx::JZKT_GET => SysFuncCost::new(100, 0),
+            SysFuncIdx::JZKT_UPDATE => SysFuncCost::new(2900, 0),
+            SysFuncIdx::JZKT_UPDATE_PREIMAGE => SysFuncCost::new(2900, 6),
+            SysFuncIdx::JZKT_REMOVE => SysFuncCost::new(2900, 0),
+            SysFuncIdx::JZKT_COMPUTE_ROOT => SysFuncCost::new(100, 0),
+            SysFuncIdx::JZKT_EMIT_LOG => SysFuncCost::new(375, 256),
+            SysFuncIdx::JZKT_COMMIT => SysFuncCost::new(100, 0),
+            SysFuncIdx::JZKT_ROLLBACK => SysFuncCost::new(100, 0),
+            SysFuncIdx::JZKT_STORE => SysFuncCost::new(2900, 0),
+            SysFuncIdx::JZKT_LOAD => SysFuncCost::new(100, 0),
+            SysFuncIdx::JZKT_PREIMAGE_SIZE => SysFuncCost::new(100, 0),
+            SysFuncIdx::JZKT_PREIMAGE_COPY => SysFuncCost::new(100, 3),
+            SysFuncIdx::JZKT_PREIMAGE_READ => SysFuncCost::new(100, 3),
         }
     }
 }
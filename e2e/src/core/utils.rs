@@ -112,7 +112,7 @@ impl<T, const IS_RUNTIME: bool> TestingContext<T, IS_RUNTIME> {
     ) -> ExecutionResult<'t, T> {
         runtime_ctx
             .with_state(if is_deploy { STATE_DEPLOY } else { STATE_MAIN })
-            .with_fuel_limit(gas_limit)
+            .with_fuel_limit(gas_limit as u64)
             .with_catch_trap(true);
         let mut runtime = Runtime::new(runtime_ctx, import_linker).unwrap();
         runtime.data_mut().clean_output();
@@ -9,41 +9,59 @@ pub fn main() {
 
     let contract_input = ExecutionContext::contract_input();
     let env_chain_id = ExecutionContext::env_chain_id();
+    let env_spec_id = ExecutionContext::env_spec_id();
+    let env_fuel_per_gas = ExecutionContext::env_fuel_per_gas();
     let contract_gas_limit = ExecutionContext::contract_gas_limit();
     let contract_address = ExecutionContext::contract_address();
     let contract_caller = ExecutionContext::contract_caller();
     let journal_checkpoint = ExecutionContext::journal_checkpoint();
     let contract_value = ExecutionContext::contract_value();
     let contract_is_static = ExecutionContext::contract_is_static();
+    let contract_depth = ExecutionContext::contract_depth();
     let block_coinbase = ExecutionContext::block_coinbase();
     let block_timestamp = ExecutionContext::block_timestamp();
     let block_number = ExecutionContext::block_number();
     let block_difficulty = ExecutionContext::block_difficulty();
     let block_gas_limit = ExecutionContext::block_gas_limit();
     let block_base_fee = ExecutionContext::block_base_fee();
+    let block_prevrandao = ExecutionContext::block_prevrandao();
 
     let tx_gas_price = ExecutionContext::tx_gas_price();
     let tx_gas_priority_fee = ExecutionContext::tx_gas_priority_fee();
     let tx_caller = ExecutionContext::tx_caller();
+    let tx_gas_limit = ExecutionContext::tx_gas_limit();
+    let tx_nonce = ExecutionContext::tx_nonce();
+    let tx_access_list = ExecutionContext::tx_access_list();
+    let tx_blob_hashes = ExecutionContext::tx_blob_hashes();
+    let tx_blob_gas_price = ExecutionContext::tx_blob_gas_price();
 
     let contract_input_struct = ContractInput {
         journal_checkpoint,
         contract_input,
         env_chain_id,
+        env_spec_id,
+        env_fuel_per_gas,
         contract_gas_limit,
         contract_address,
         contract_caller,
         contract_value,
         contract_is_static,
+        contract_depth,
         block_coinbase,
         block_timestamp,
         block_number,
         block_difficulty,
         block_gas_limit,
         block_base_fee,
+        block_prevrandao,
         tx_gas_price,
         tx_gas_priority_fee,
         tx_caller,
+        tx_gas_limit,
+        tx_nonce,
+        tx_access_list,
+        tx_blob_hashes,
+        tx_blob_gas_price,
     };
     ctx.fast_return_and_exit(
         contract_input_struct.encode_to_vec(0),
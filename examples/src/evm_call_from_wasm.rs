@@ -27,6 +27,7 @@ pub fn main() {
     let contract_input_vec = contract_input.encode_to_vec(0);
     let account = Account::new_from_jzkt(&evm_contract_address);
     let rwasm_bytecode_hash = account.rwasm_bytecode_hash;
+    let mut fuel = ExecutionContext::gas_to_fuel(gas_limit as u64);
 
     let exit_code = LowLevelSDK::sys_exec_hash(
         rwasm_bytecode_hash.as_ptr(),
@@ -34,7 +35,7 @@ pub fn main() {
         contract_input_vec.len() as u32,
         core::ptr::null_mut(),
         0,
-        &gas_limit as *const u32,
+        &mut fuel as *mut u64,
         STATE_MAIN,
     );
     if exit_code != ExitCode::Ok.into_i32() {